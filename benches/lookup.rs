@@ -0,0 +1,92 @@
+//! Compares the lookup of fields by name in a struct with many fields against a linear `match`.
+//!
+//! Run with `cargo bench --bench lookup`.
+
+use core::any::Any;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use field_access::{AnyFieldAccess, FieldAccess};
+
+macro_rules! wide_structs {
+    ($($field:ident)*) => {
+        /// Uses the lookup generated by `#[derive(FieldAccess)]`.
+        #[derive(FieldAccess, Default)]
+        struct Derived {
+            $($field: u64,)*
+        }
+
+        /// Uses a `match` on the field name, which `#[derive(FieldAccess)]` generates for structs
+        /// with few fields.
+        #[derive(Default)]
+        struct Linear {
+            $($field: u64,)*
+        }
+
+        impl AnyFieldAccess for Linear {
+            fn field_as_any(&self, field: &str) -> Option<&dyn Any> {
+                match field {
+                    $(stringify!($field) => Some(&self.$field),)*
+                    _ => None,
+                }
+            }
+
+            fn field_as_any_mut(&mut self, field: &str) -> Option<&mut dyn Any> {
+                match field {
+                    $(stringify!($field) => Some(&mut self.$field),)*
+                    _ => None,
+                }
+            }
+
+            fn field_names(&self) -> &'static [&'static str] {
+                &[$(stringify!($field)),*]
+            }
+        }
+    };
+}
+
+wide_structs! {
+    metric_000 metric_001 metric_002 metric_003 metric_004 metric_005 metric_006 metric_007
+    metric_008 metric_009 metric_010 metric_011 metric_012 metric_013 metric_014 metric_015
+    metric_016 metric_017 metric_018 metric_019 metric_020 metric_021 metric_022 metric_023
+    metric_024 metric_025 metric_026 metric_027 metric_028 metric_029 metric_030 metric_031
+    metric_032 metric_033 metric_034 metric_035 metric_036 metric_037 metric_038 metric_039
+    metric_040 metric_041 metric_042 metric_043 metric_044 metric_045 metric_046 metric_047
+    metric_048 metric_049 metric_050 metric_051 metric_052 metric_053 metric_054 metric_055
+    metric_056 metric_057 metric_058 metric_059 metric_060 metric_061 metric_062 metric_063
+    metric_064 metric_065 metric_066 metric_067 metric_068 metric_069 metric_070 metric_071
+    metric_072 metric_073 metric_074 metric_075 metric_076 metric_077 metric_078 metric_079
+    metric_080 metric_081 metric_082 metric_083 metric_084 metric_085 metric_086 metric_087
+    metric_088 metric_089 metric_090 metric_091 metric_092 metric_093 metric_094 metric_095
+    metric_096 metric_097 metric_098 metric_099 metric_100 metric_101 metric_102 metric_103
+    metric_104 metric_105 metric_106 metric_107 metric_108 metric_109 metric_110 metric_111
+    metric_112 metric_113 metric_114 metric_115 metric_116 metric_117 metric_118 metric_119
+    metric_120 metric_121 metric_122 metric_123 metric_124 metric_125 metric_126 metric_127
+    metric_128 metric_129 metric_130 metric_131 metric_132 metric_133 metric_134 metric_135
+    metric_136 metric_137 metric_138 metric_139 metric_140 metric_141 metric_142 metric_143
+    metric_144 metric_145 metric_146 metric_147 metric_148 metric_149 metric_150 metric_151
+    metric_152 metric_153 metric_154 metric_155 metric_156 metric_157 metric_158 metric_159
+    metric_160 metric_161 metric_162 metric_163 metric_164 metric_165 metric_166 metric_167
+    metric_168 metric_169 metric_170 metric_171 metric_172 metric_173 metric_174 metric_175
+    metric_176 metric_177 metric_178 metric_179 metric_180 metric_181 metric_182 metric_183
+    metric_184 metric_185 metric_186 metric_187 metric_188 metric_189 metric_190 metric_191
+    metric_192 metric_193 metric_194 metric_195 metric_196 metric_197 metric_198 metric_199
+}
+
+fn lookup(c: &mut Criterion) {
+    let derived = Derived::default();
+    let linear = Linear::default();
+    let mut group = c.benchmark_group("lookup");
+
+    for name in ["metric_000", "metric_100", "metric_199", "metric_200"] {
+        group.bench_with_input(BenchmarkId::new("derived", name), name, |b, name| {
+            b.iter(|| black_box(&derived).field(black_box(name)).is_some());
+        });
+        group.bench_with_input(BenchmarkId::new("linear", name), name, |b, name| {
+            b.iter(|| black_box(&linear).field(black_box(name)).is_some());
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, lookup);
+criterion_main!(benches);
@@ -0,0 +1,240 @@
+use crate::case::RenameRule;
+use std::str::FromStr;
+use syn::{
+    ext::IdentExt, meta::ParseNestedMeta, Attribute, Error, Expr, ExprLit, ExprUnary, Lit, LitStr,
+    Meta, Path, Result, UnOp,
+};
+
+/// Attributes on structs and enums.
+#[derive(Default)]
+pub struct ContainerAttrs {
+    pub rename_all: Option<RenameRule>,
+    pub keys: Option<Path>,
+}
+
+impl ContainerAttrs {
+    pub fn parse(attrs: &[Attribute]) -> Result<ContainerAttrs> {
+        let mut container_attrs = ContainerAttrs::default();
+
+        for attr in attrs {
+            if !attr.path().is_ident("field_access") {
+                continue;
+            }
+
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename_all") {
+                    if container_attrs.rename_all.is_some() {
+                        return Err(meta.error("duplicate `rename_all` attribute"));
+                    }
+
+                    let value: LitStr = meta.value()?.parse()?;
+                    container_attrs.rename_all = Some(RenameRule::from_lit(&value)?);
+                    Ok(())
+                } else if meta.path.is_ident("keys") {
+                    if container_attrs.keys.is_some() {
+                        return Err(meta.error("duplicate `keys` attribute"));
+                    }
+
+                    container_attrs.keys = Some(meta.path);
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported field_access attribute"))
+                }
+            })?;
+        }
+
+        Ok(container_attrs)
+    }
+}
+
+/// Attributes on struct and variant fields.
+#[derive(Default)]
+pub struct FieldAttrs {
+    pub doc: Option<String>,
+    pub rename: Option<String>,
+    pub aliases: Vec<String>,
+    pub meta: Vec<(String, MetaValue)>,
+    pub groups: Vec<String>,
+    pub skip: bool,
+    pub flatten: bool,
+    pub readonly: bool,
+    pub writeonly: bool,
+}
+
+impl FieldAttrs {
+    pub fn parse(attrs: &[Attribute]) -> Result<FieldAttrs> {
+        let mut field_attrs = FieldAttrs {
+            doc: parse_doc(attrs),
+            ..FieldAttrs::default()
+        };
+
+        for attr in attrs {
+            if !attr.path().is_ident("field_access") {
+                continue;
+            }
+
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    if field_attrs.rename.is_some() {
+                        return Err(meta.error("duplicate `rename` attribute"));
+                    }
+
+                    let value: LitStr = meta.value()?.parse()?;
+                    field_attrs.rename = Some(value.value());
+                    Ok(())
+                } else if meta.path.is_ident("alias") {
+                    let value: LitStr = meta.value()?.parse()?;
+                    field_attrs.aliases.push(value.value());
+                    Ok(())
+                } else if meta.path.is_ident("meta") {
+                    meta.parse_nested_meta(|item| {
+                        let (key, value) = parse_meta_item(&item)?;
+
+                        if field_attrs.meta.iter().any(|(other, _)| *other == key) {
+                            return Err(item.error(format!("duplicate meta key `{key}`")));
+                        }
+
+                        field_attrs.meta.push((key, value));
+                        Ok(())
+                    })
+                } else if meta.path.is_ident("group") {
+                    let value: LitStr = meta.value()?.parse()?;
+                    let group = value.value();
+
+                    if field_attrs.groups.contains(&group) {
+                        return Err(meta.error(format!("duplicate group `{group}`")));
+                    }
+
+                    field_attrs.groups.push(group);
+                    Ok(())
+                } else if meta.path.is_ident("skip") {
+                    field_attrs.skip = true;
+                    Ok(())
+                } else if meta.path.is_ident("flatten") {
+                    field_attrs.flatten = true;
+                    Ok(())
+                } else if meta.path.is_ident("readonly") {
+                    if field_attrs.writeonly {
+                        return Err(meta.error("`readonly` conflicts with `writeonly`"));
+                    }
+
+                    field_attrs.readonly = true;
+                    Ok(())
+                } else if meta.path.is_ident("writeonly") {
+                    if field_attrs.readonly {
+                        return Err(meta.error("`writeonly` conflicts with `readonly`"));
+                    }
+
+                    field_attrs.writeonly = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported field_access attribute"))
+                }
+            })?;
+        }
+
+        Ok(field_attrs)
+    }
+}
+
+/// Collects the doc comments from `attrs`.
+///
+/// Like rustdoc, the common indentation of all lines is removed. Doc attributes whose value is not
+/// a string literal, e.g. `#[doc = include_str!("...")]`, are ignored.
+fn parse_doc(attrs: &[Attribute]) -> Option<String> {
+    let docs: Vec<String> = attrs
+        .iter()
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(meta) if meta.path.is_ident("doc") => match &meta.value {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(lit), ..
+                }) => Some(lit.value()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect();
+
+    let lines: Vec<&str> = docs.iter().flat_map(|doc| doc.split('\n')).collect();
+    let indent = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()?;
+
+    let doc = lines
+        .iter()
+        .map(|line| line.get(indent..).unwrap_or_default().trim_end())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    Some(doc.trim_matches('\n').to_owned())
+}
+
+/// The value of a `#[field_access(meta(key = value))]` item.
+pub enum MetaValue {
+    Str(String),
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+}
+
+/// Parses a `key = value` or `key` item of a `meta(...)` attribute.
+///
+/// A key without value is equivalent to `key = true`.
+fn parse_meta_item(item: &ParseNestedMeta) -> Result<(String, MetaValue)> {
+    let key = match item.path.get_ident() {
+        Some(ident) => ident.unraw().to_string(),
+        None => return Err(item.error("expected meta key")),
+    };
+
+    if item.input.is_empty() || item.input.peek(syn::Token![,]) {
+        return Ok((key, MetaValue::Bool(true)));
+    }
+
+    let expr: Expr = item.value()?.parse()?;
+    Ok((key, parse_meta_value(&expr)?))
+}
+
+fn parse_meta_value(expr: &Expr) -> Result<MetaValue> {
+    let unsupported = || {
+        Error::new_spanned(
+            expr,
+            "meta values must be string, boolean, integer or float literals",
+        )
+    };
+
+    // Negative numbers are unary expressions rather than literals.
+    let (sign, lit) = match expr {
+        Expr::Lit(ExprLit { lit, .. }) => ("", lit),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr: inner,
+            ..
+        }) => match &**inner {
+            Expr::Lit(ExprLit {
+                lit: lit @ (Lit::Int(_) | Lit::Float(_)),
+                ..
+            }) => ("-", lit),
+            _ => return Err(unsupported()),
+        },
+        _ => return Err(unsupported()),
+    };
+
+    match lit {
+        Lit::Str(lit) => Ok(MetaValue::Str(lit.value())),
+        Lit::Bool(lit) => Ok(MetaValue::Bool(lit.value)),
+        Lit::Int(lit) => parse_number(expr, sign, lit.base10_digits()).map(MetaValue::Int),
+        Lit::Float(lit) => match parse_number::<f64>(expr, sign, lit.base10_digits())? {
+            value if value.is_finite() => Ok(MetaValue::Float(value)),
+            _ => Err(Error::new_spanned(expr, "meta value out of range")),
+        },
+        _ => Err(unsupported()),
+    }
+}
+
+fn parse_number<T: FromStr>(expr: &Expr, sign: &str, digits: &str) -> Result<T> {
+    format!("{sign}{digits}")
+        .parse()
+        .map_err(|_| Error::new_spanned(expr, "meta value out of range"))
+}
@@ -1,75 +1,1242 @@
-use proc_macro2::TokenStream;
-use quote::quote;
-use syn::{punctuated::Punctuated, Data, DeriveInput, Error, Field, Result, Token};
+use crate::attr::{ContainerAttrs, FieldAttrs, MetaValue};
+use proc_macro2::{Literal, Span, TokenStream};
+use quote::{format_ident, quote};
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::iter;
+use syn::{
+    ext::IdentExt, parse_quote, Data, DeriveInput, Error, Fields, Generics, Ident, Index, Lifetime,
+    Member, Result, Type, Visibility,
+};
 
 pub fn derive(input: &DeriveInput) -> Result<TokenStream> {
     let name = &input.ident;
+    let attrs = ContainerAttrs::parse(&input.attrs)?;
 
-    let fields: Vec<_> = fields(input)?
-        .iter()
-        .map(|field| {
-            let name = field.ident.as_ref().expect("field has a name");
-            let name_str = name.to_string();
-            (name, name_str)
-        })
+    let variants = match &input.data {
+        Data::Struct(data) => vec![Variant::new(None, &data.fields, &attrs)?],
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .map(|variant| Variant::new(Some(&variant.ident), &variant.fields, &attrs))
+            .collect::<Result<_>>()?,
+        Data::Union(_) => {
+            return Err(Error::new_spanned(
+                input,
+                "FieldAccess does not support unions",
+            ))
+        }
+    };
+
+    let target = Target::new(&input.generics)?;
+
+    if let Target::Borrowed(lifetime) = &target {
+        if let Some(field) = variants.iter().flat_map(Variant::flattened).next() {
+            return Err(Error::new_spanned(
+                &field.ty,
+                format_args!(
+                    "`flatten` is not supported for types with lifetime parameter `{lifetime}`"
+                ),
+            ));
+        }
+    }
+
+    if let Some(keys) = &attrs.keys {
+        if matches!(input.data, Data::Enum(_)) {
+            return Err(Error::new_spanned(
+                keys,
+                "`keys` is only supported for structs",
+            ));
+        }
+
+        if let Target::Borrowed(lifetime) = &target {
+            return Err(Error::new_spanned(
+                keys,
+                format_args!(
+                    "`keys` is not supported for types with lifetime parameter `{lifetime}`"
+                ),
+            ));
+        }
+    }
+
+    let body = expand_variants(&target, &variants, matches!(input.data, Data::Enum(_)));
+    let trait_path = target.trait_path();
+
+    let generics = add_trait_bounds(input.generics.clone());
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let keys = attrs.keys.is_some().then(|| expand_keys(&variants[0]));
+
+    let field_table = matches!(target, Target::Any).then(|| {
+        let table = expand_field_table(&variants);
+        let vis = &input.vis;
+
+        quote! {
+            impl #impl_generics ::field_access::__private::FieldTable for #name #ty_generics #where_clause {
+                #table
+            }
+
+            impl #impl_generics #name #ty_generics #where_clause {
+                /// Static information about the fields of this type, see `field_access::FieldInfo`.
+                #vis const FIELDS: &'static [::field_access::FieldInfo] =
+                    <Self as ::field_access::__private::FieldTable>::FIELDS;
+
+                #keys
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics #trait_path for #name #ty_generics #where_clause {
+            #body
+        }
+
+        #field_table
+    })
+}
+
+// Adds an `Any` bound to every type parameter since the field values are type-erased via `Any`.
+fn add_trait_bounds(mut generics: Generics) -> Generics {
+    let params: Vec<_> = generics
+        .type_params()
+        .map(|param| param.ident.clone())
         .collect();
 
-    let field_names = fields.iter().map(|(_, name_str)| quote!(#name_str));
+    let where_clause = generics.make_where_clause();
+
+    for param in params {
+        where_clause
+            .predicates
+            .push(parse_quote!(#param: ::core::any::Any));
+    }
+
+    generics
+}
+
+/// The trait to implement.
+///
+/// Types without lifetime parameters implement `AnyFieldAccess`, types with a single lifetime
+/// parameter implement `BorrowedFieldAccess<'a>`.
+enum Target {
+    Any,
+    Borrowed(Lifetime),
+}
+
+impl Target {
+    fn new(generics: &Generics) -> Result<Target> {
+        let mut lifetimes = generics.lifetimes();
+
+        match (lifetimes.next(), lifetimes.next()) {
+            (None, _) => Ok(Target::Any),
+            (Some(param), None) => Ok(Target::Borrowed(param.lifetime.clone())),
+            (Some(_), Some(param)) => Err(Error::new_spanned(
+                param,
+                "FieldAccess does not support more than one lifetime parameter",
+            )),
+        }
+    }
+
+    fn trait_path(&self) -> TokenStream {
+        match self {
+            Target::Any => quote!(::field_access::AnyFieldAccess),
+            Target::Borrowed(lifetime) => quote!(::field_access::BorrowedFieldAccess<#lifetime>),
+        }
+    }
+
+    fn field_fn(&self) -> TokenStream {
+        match self {
+            Target::Any => quote! {
+                fn field_as_any(&self, field: &str) -> ::core::option::Option<&dyn ::core::any::Any>
+            },
+            Target::Borrowed(lifetime) => quote! {
+                fn field(&self, field: &str) -> ::core::option::Option<::field_access::BorrowedField<'_, #lifetime>>
+            },
+        }
+    }
+
+    fn field_mut_fn(&self) -> TokenStream {
+        match self {
+            Target::Any => quote! {
+                fn field_as_any_mut(&mut self, field: &str) -> ::core::option::Option<&mut dyn ::core::any::Any>
+            },
+            Target::Borrowed(lifetime) => quote! {
+                fn field_mut(&mut self, field: &str) -> ::core::option::Option<::field_access::BorrowedFieldMut<'_, #lifetime>>
+            },
+        }
+    }
+
+    // Generates an expression which resolves the looked up name `field` to its index in
+    // `field_names`. Only `AnyFieldAccess` has a method for this.
+    fn field_index(&self, variants: &[Variant]) -> TokenStream {
+        match self {
+            Target::Any => quote!(::field_access::AnyFieldAccess::field_index(self, field)),
+            Target::Borrowed(_) => index_of(variants),
+        }
+    }
+
+    // Wraps a binding to a field in the value returned by the field access methods.
+    fn field_value(&self, field: &FieldSpec, mutable: bool) -> TokenStream {
+        let binding = &field.binding;
+
+        match (self, mutable) {
+            (Target::Any, false) => quote!(#binding as &dyn ::core::any::Any),
+            (Target::Any, true) => quote!(#binding as &mut dyn ::core::any::Any),
+            (Target::Borrowed(lifetime), false) if field.borrows(lifetime) => {
+                quote!(::field_access::BorrowedField::borrowed(#binding))
+            }
+            (Target::Borrowed(_), false) => {
+                quote!(::field_access::BorrowedField::owned(#binding))
+            }
+            (Target::Borrowed(lifetime), true) if field.borrows(lifetime) => {
+                quote!(::field_access::BorrowedFieldMut::borrowed(#binding))
+            }
+            (Target::Borrowed(_), true) => {
+                quote!(::field_access::BorrowedFieldMut::owned(#binding))
+            }
+        }
+    }
+}
+
+struct FieldSpec {
+    member: Member,
+    binding: Ident,
+    name: String,
+    doc: Option<String>,
+    aliases: Vec<String>,
+    meta: Vec<(String, MetaValue)>,
+    groups: Vec<String>,
+    vis: Visibility,
+    ty: Type,
+    flatten: bool,
+    readable: bool,
+    writable: bool,
+}
+
+impl FieldSpec {
+    // The field name followed by its aliases.
+    fn names(&self) -> impl Iterator<Item = &String> {
+        iter::once(&self.name).chain(&self.aliases)
+    }
+
+    // Returns `true` if the field can be accessed immutably or mutably, respectively.
+    fn accessible(&self, mutable: bool) -> bool {
+        if mutable {
+            self.writable
+        } else {
+            self.readable
+        }
+    }
+
+    // Returns `true` if the field is a shared reference with the given lifetime.
+    //
+    // References with any other lifetime, e.g. `&'static str`, are handled like other values.
+    fn borrows(&self, lifetime: &Lifetime) -> bool {
+        matches!(
+            &self.ty,
+            Type::Reference(ty) if ty.mutability.is_none() && ty.lifetime.as_ref() == Some(lifetime)
+        )
+    }
+}
+
+/// A struct or an enum variant.
+struct Variant {
+    ident: Option<Ident>,
+    fields: Vec<FieldSpec>,
+}
+
+impl Variant {
+    fn new(
+        ident: Option<&Ident>,
+        fields: &Fields,
+        container_attrs: &ContainerAttrs,
+    ) -> Result<Variant> {
+        let mut specs: Vec<FieldSpec> = Vec::with_capacity(fields.len());
+
+        for (index, field) in fields.iter().enumerate() {
+            let attrs = FieldAttrs::parse(&field.attrs)?;
+
+            if attrs.skip {
+                continue;
+            }
+
+            let binding = Ident::new(&format!("__field{index}"), Span::call_site());
+            let (member, name) = match &field.ident {
+                Some(ident) => {
+                    let name = ident.unraw().to_string();
+                    let name = match container_attrs.rename_all {
+                        Some(rule) => rule.apply(&name),
+                        None => name,
+                    };
+                    (Member::Named(ident.clone()), name)
+                }
+                None => (Member::Unnamed(Index::from(index)), index.to_string()),
+            };
+            if attrs.flatten {
+                if attrs.rename.is_some()
+                    || !attrs.aliases.is_empty()
+                    || !attrs.meta.is_empty()
+                    || !attrs.groups.is_empty()
+                {
+                    return Err(Error::new_spanned(
+                        field,
+                        "`flatten` cannot be combined with `rename`, `alias`, `meta` or `group`",
+                    ));
+                }
+
+                // The flattened struct is handed out via `AnyFieldAccess::flattened`, which would
+                // expose the fields of a write-only struct for reading.
+                if attrs.writeonly {
+                    return Err(Error::new_spanned(
+                        field,
+                        "`flatten` cannot be combined with `writeonly`",
+                    ));
+                }
+
+                specs.push(FieldSpec {
+                    member,
+                    binding,
+                    name,
+                    doc: attrs.doc,
+                    aliases: Vec::new(),
+                    meta: Vec::new(),
+                    groups: Vec::new(),
+                    vis: field.vis.clone(),
+                    ty: field.ty.clone(),
+                    flatten: true,
+                    readable: !attrs.writeonly,
+                    writable: !attrs.readonly,
+                });
+                continue;
+            }
+
+            let name = attrs.rename.unwrap_or(name);
+
+            for name in iter::once(&name).chain(&attrs.aliases) {
+                if specs
+                    .iter()
+                    .any(|spec| !spec.flatten && spec.names().any(|other| other == name))
+                {
+                    return Err(Error::new_spanned(
+                        field,
+                        format!("duplicate field name `{name}`"),
+                    ));
+                }
+            }
+
+            specs.push(FieldSpec {
+                member,
+                binding,
+                name,
+                doc: attrs.doc,
+                aliases: attrs.aliases,
+                meta: attrs.meta,
+                groups: attrs.groups,
+                vis: field.vis.clone(),
+                ty: field.ty.clone(),
+                flatten: false,
+                readable: !attrs.writeonly,
+                writable: !attrs.readonly,
+            });
+        }
 
-    let immutable_arms = fields.iter().map(|(name, name_str)| {
-        quote!(#name_str => {
-            Some(&self.#name as &dyn ::core::any::Any)
+        Ok(Variant {
+            ident: ident.cloned(),
+            fields: specs,
         })
+    }
+
+    // Fields which are accessible by name.
+    fn fields(&self) -> impl Iterator<Item = &FieldSpec> {
+        self.fields.iter().filter(|field| !field.flatten)
+    }
+
+    // Fields whose own fields are accessible as if they were fields of this variant.
+    fn flattened(&self) -> impl Iterator<Item = &FieldSpec> {
+        self.fields.iter().filter(|field| field.flatten)
+    }
+
+    // Returns `true` if `field` is a flattened field whose field names shadow the fields of the
+    // flattened fields after it.
+    fn shadows(&self, field: &FieldSpec) -> bool {
+        field.flatten
+            && self
+                .flattened()
+                .last()
+                .map_or(false, |last| last.binding != field.binding)
+    }
+
+    fn path(&self) -> TokenStream {
+        match &self.ident {
+            Some(ident) => quote!(Self::#ident),
+            None => quote!(Self),
+        }
+    }
+
+    // A pattern which binds all fields matching the predicate.
+    fn pattern(&self, predicate: impl Fn(&FieldSpec) -> bool) -> TokenStream {
+        let path = self.path();
+        let fields: Vec<_> = self
+            .fields
+            .iter()
+            .filter(|field| predicate(field))
+            .collect();
+        let members = fields.iter().map(|field| &field.member);
+        let bindings = fields.iter().map(|field| &field.binding);
+        quote!(#path { #(#members: #bindings,)* .. })
+    }
+}
+
+fn expand_variants(target: &Target, variants: &[Variant], is_enum: bool) -> TokenStream {
+    let field_fn = target.field_fn();
+    let field_mut_fn = target.field_mut_fn();
+
+    if variants.is_empty() {
+        return quote! {
+            #[allow(unused_variables)]
+            #field_fn {
+                match *self {}
+            }
+
+            #[allow(unused_variables)]
+            #field_mut_fn {
+                match *self {}
+            }
+
+            fn field_names(&self) -> &'static [&'static str] {
+                match *self {}
+            }
+
+            fn variant_name(&self) -> ::core::option::Option<&'static str> {
+                match *self {}
+            }
+        };
+    }
+
+    let lookup_fn = |mutable: bool| {
+        let by_index = match target {
+            Target::Any if mutable => {
+                quote!(::field_access::AnyFieldAccess::field_at_mut(self, index))
+            }
+            Target::Any => quote!(::field_access::AnyFieldAccess::field_at(self, index)),
+            Target::Borrowed(_) => index_arms(variants, mutable, |field| {
+                let value = target.field_value(field, mutable);
+                quote!(Some(#value))
+            }),
+        };
+        let forward = if mutable {
+            quote!(field_as_any_mut)
+        } else {
+            quote!(field_as_any)
+        };
+
+        lookup(
+            target,
+            variants,
+            &by_index,
+            |field| {
+                let binding = &field.binding;
+                field
+                    .accessible(mutable)
+                    .then(|| quote!(::field_access::AnyFieldAccess::#forward(#binding, field)))
+            },
+            &quote!(None),
+        )
+    };
+    let field_body = lookup_fn(false);
+    let field_mut_body = lookup_fn(true);
+    let dyn_lookup_fns = matches!(target, Target::Any).then(|| {
+        let field_access = DynLookup::field_access().expand(variants);
+        let sequence = DynLookup::sequence().expand(variants);
+        quote!(#field_access #sequence)
     });
+    let field_ref_fns = matches!(target, Target::Any).then(|| expand_field_ref_fns(variants));
+    let field_table_fns = matches!(target, Target::Any).then(|| expand_field_table_fns(variants));
+    let index_fns = matches!(target, Target::Any).then(|| expand_index_fns(variants));
 
-    let mutable_arms = fields.iter().map(|(name, name_str)| {
-        quote!(#name_str => {
-            Some(&mut self.#name as &mut dyn ::core::any::Any)
-        })
+    let field_names_arms = variants.iter().map(|variant| {
+        let path = variant.path();
+        let field_names = variant.fields().map(|field| &field.name);
+        quote!(#path { .. } => &[#(#field_names),*])
     });
 
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let has_aliases = variants
+        .iter()
+        .flat_map(Variant::fields)
+        .any(|field| !field.aliases.is_empty());
+    let has_flattened = variants
+        .iter()
+        .flat_map(Variant::flattened)
+        .next()
+        .is_some();
 
-    Ok(quote! {
-        impl #impl_generics ::field_access::AnyFieldAccess for #name #ty_generics #where_clause {
-            fn field_as_any(&self, field: &str) -> ::core::option::Option<&dyn ::core::any::Any> {
-                match field {
-                    #(#immutable_arms)*
-                    _ => None
+    let field_aliases = (has_aliases || has_flattened).then(|| {
+        let arms = variants.iter().map(|variant| {
+            let path = variant.path();
+            let arms = variant
+                .fields()
+                .enumerate()
+                .filter(|(_, field)| !field.aliases.is_empty())
+                .map(|(index, field)| {
+                    let name = &field.name;
+                    let aliases = &field.aliases;
+                    // Aliases are not resolved to the aliases of their field.
+                    quote!(#index if field == #name => &[#(#aliases),*],)
+                });
+
+            quote!(#path { .. } => match index {
+                #(#arms)*
+                _ => &[]
+            })
+        });
+        let lookup = lookup(
+            target,
+            variants,
+            &quote! {
+                match self {
+                    #(#arms,)*
                 }
+            },
+            |field| {
+                let binding = &field.binding;
+                Some(quote!(::field_access::AnyFieldAccess::field_aliases(#binding, field)))
+            },
+            &quote!(&[]),
+        );
+
+        quote! {
+            fn field_aliases(&self, field: &str) -> &'static [&'static str] {
+                #lookup
             }
+        }
+    });
 
-            fn field_as_any_mut(&mut self, field: &str) -> ::core::option::Option<&mut dyn ::core::any::Any> {
-                match field {
-                    #(#mutable_arms)*
-                    _ => None
+    let flattened = has_flattened.then(|| {
+        let arms = variants.iter().map(|variant| {
+            let pattern = variant.pattern(|field| field.flatten);
+            let arms = variant.flattened().enumerate().map(|(index, field)| {
+                let binding = &field.binding;
+                quote!(#index => Some(#binding as &dyn ::field_access::AnyFieldAccess),)
+            });
+
+            quote!(#pattern => match index {
+                #(#arms)*
+                _ => None
+            })
+        });
+
+        quote! {
+            fn flattened(&self, index: usize) -> ::core::option::Option<&dyn ::field_access::AnyFieldAccess> {
+                match self {
+                    #(#arms,)*
                 }
             }
+        }
+    });
 
-            fn field_names(&self) -> &'static [&'static str] {
-                &[#(#field_names),*]
+    let variant_name = is_enum.then(|| {
+        let arms = variants.iter().map(|variant| {
+            let path = variant.path();
+            let name = variant
+                .ident
+                .as_ref()
+                .map(|ident| ident.unraw().to_string());
+            quote!(#path { .. } => Some(#name))
+        });
+
+        quote! {
+            fn variant_name(&self) -> ::core::option::Option<&'static str> {
+                match self {
+                    #(#arms,)*
+                }
             }
         }
-    })
+    });
+
+    quote! {
+        #field_fn {
+            #field_body
+        }
+
+        #field_mut_fn {
+            #field_mut_body
+        }
+
+        fn field_names(&self) -> &'static [&'static str] {
+            match self {
+                #(#field_names_arms,)*
+            }
+        }
+
+        #dyn_lookup_fns
+
+        #field_ref_fns
+
+        #field_table_fns
+
+        #index_fns
+
+        #field_aliases
+
+        #flattened
+
+        #variant_name
+    }
 }
 
-type Fields = Punctuated<Field, Token![,]>;
+// Generates `field_ref` and `field_ref_mut`, which resolve the value of a field together with its
+// conversion into `&dyn AnyFieldAccess` and its type name in a single lookup.
+fn expand_field_ref_fns(variants: &[Variant]) -> TokenStream {
+    let lookup_fn = |mutable: bool| {
+        let forward = if mutable {
+            quote!(field_ref_mut)
+        } else {
+            quote!(field_ref)
+        };
+        let by_index = index_arms(variants, mutable, |field| {
+            let value = field_ref_value(field, mutable);
+            quote!(Some(#value))
+        });
+
+        lookup(
+            &Target::Any,
+            variants,
+            &by_index,
+            |field| {
+                let binding = &field.binding;
+                field
+                    .accessible(mutable)
+                    .then(|| quote!(::field_access::AnyFieldAccess::#forward(#binding, field)))
+            },
+            &quote!(None),
+        )
+    };
+    let body = lookup_fn(false);
+    let body_mut = lookup_fn(true);
+
+    quote! {
+        fn field_ref(&self, field: &str) -> ::core::option::Option<::field_access::Field<'_>> {
+            #[allow(unused_imports)]
+            use ::field_access::__private::{AsFieldAccess as _, AsFieldAccessFallback as _};
+
+            #body
+        }
 
-fn fields(input: &DeriveInput) -> Result<&Fields> {
-    use syn::Fields;
+        fn field_ref_mut(&mut self, field: &str) -> ::core::option::Option<::field_access::FieldMut<'_>> {
+            #[allow(unused_imports)]
+            use ::field_access::__private::{AsFieldAccess as _, AsFieldAccessFallback as _};
 
-    let unsupported = match &input.data {
-        Data::Struct(data) => match &data.fields {
-            Fields::Named(fields) => return Ok(&fields.named),
-            Fields::Unnamed(_) => "newtype structs",
-            Fields::Unit => "unit structs",
+            #body_mut
+        }
+    }
+}
+
+// Wraps a binding to a field in the `Field` or `FieldMut` returned by `field_ref` and
+// `field_ref_mut`.
+fn field_ref_value(field: &FieldSpec, mutable: bool) -> TokenStream {
+    let binding = &field.binding;
+
+    if mutable {
+        quote! {{
+            let access = (&::field_access::__private::Wrap::new(#binding)).field_access_mut_fn();
+            ::field_access::__private::field_mut(#binding, access)
+        }}
+    } else {
+        quote! {
+            ::field_access::__private::field(
+                #binding,
+                (&::field_access::__private::Wrap::new(#binding)).field_access_fn(),
+            )
+        }
+    }
+}
+
+// Generates the body of the `FieldTable` implementation.
+fn expand_field_table(variants: &[Variant]) -> TokenStream {
+    let field_types = variants.iter().map(|variant| {
+        let types = variant.fields().map(|field| {
+            let name = &field.name;
+            let ty = &field.ty;
+            let ty_string = type_string(ty);
+            quote! {
+                ::field_access::FieldType::new(
+                    #name,
+                    #ty_string,
+                    ::core::any::type_name::<#ty>,
+                    ::core::any::TypeId::of::<#ty>,
+                )
+            }
+        });
+
+        quote!(&[#(#types),*])
+    });
+
+    let fields = variants
+        .iter()
+        .enumerate()
+        .flat_map(|(variant_index, variant)| {
+            variant.fields().enumerate().map(move |(index, field)| {
+                let name = &field.name;
+                let variant_name = variant.ident.as_ref().map(|ident| {
+                    let ident = ident.unraw().to_string();
+                    quote!(.with_variant(#ident))
+                });
+                let doc = field.doc.as_ref().map(|doc| quote!(.with_doc(#doc)));
+                let aliases = (!field.aliases.is_empty()).then(|| {
+                    let aliases = &field.aliases;
+                    quote!(.with_aliases(&[#(#aliases),*]))
+                });
+                let meta = (!field.meta.is_empty()).then(|| {
+                    let items = field.meta.iter().map(|(key, value)| {
+                        let value = match value {
+                            MetaValue::Str(value) => quote!(Str(#value)),
+                            MetaValue::Bool(value) => quote!(Bool(#value)),
+                            MetaValue::Int(value) => quote!(Int(#value)),
+                            MetaValue::Float(value) => quote!(Float(#value)),
+                        };
+                        quote!((#key, ::field_access::MetaValue::#value))
+                    });
+                    quote!(.with_meta(&[#(#items),*]))
+                });
+                let groups = (!field.groups.is_empty()).then(|| {
+                    let groups = &field.groups;
+                    quote!(.with_groups(&[#(#groups),*]))
+                });
+                let access = match (field.readable, field.writable) {
+                    (true, false) => Some(quote!(.readonly())),
+                    (false, true) => Some(quote!(.writeonly())),
+                    _ => None,
+                };
+
+                quote! {
+                    ::field_access::FieldInfo::new(
+                        #name,
+                        #index,
+                        &<Self as ::field_access::__private::FieldTable>::FIELD_TYPES[#variant_index][#index],
+                    )
+                    #variant_name
+                    #doc
+                    #aliases
+                    #meta
+                    #groups
+                    #access
+                }
+            })
+        });
+
+    quote! {
+        const FIELD_TYPES: &'static [&'static [::field_access::FieldType]] = &[#(#field_types),*];
+
+        const FIELDS: &'static [::field_access::FieldInfo] = &[#(#fields),*];
+    }
+}
+
+// Generates the associated functions which return the `FieldKey`s of a struct.
+fn expand_keys(variant: &Variant) -> TokenStream {
+    let fns = variant.fields().enumerate().map(|(index, field)| {
+        let key = match &field.member {
+            Member::Named(ident) => format_ident!("key_{}", ident.unraw()),
+            Member::Unnamed(member) => format_ident!("key_{}", member.index),
+        };
+        let doc = format!(
+            "Key for the field `{}`, see `field_access::FieldKey`.",
+            field.name
+        );
+        let vis = &field.vis;
+        let ty = &field.ty;
+        let name = &field.name;
+        let member = &field.member;
+        let access = match (field.readable, field.writable) {
+            (true, false) => quote!(ReadOnly),
+            (false, true) => quote!(WriteOnly),
+            _ => quote!(ReadWrite),
+        };
+
+        quote! {
+            #[doc = #doc]
+            #[inline]
+            #vis fn #key() -> ::field_access::FieldKey<Self, #ty, ::field_access::#access> {
+                ::field_access::FieldKey::new(
+                    #name,
+                    #index,
+                    |value: &Self| &value.#member,
+                    |value: &mut Self| &mut value.#member,
+                )
+            }
+        }
+    });
+
+    quote!(#(#fns)*)
+}
+
+// Renders a type as it would be written in source code.
+//
+// `stringify!` cannot be used for this since tokens passed through a proc macro lose their
+// original spacing, which would render e.g. `Vec<u8>` as `Vec < u8 >`. Instead, the spaces which
+// the token printer inserts are removed where `rustfmt` would not put them.
+fn type_string(ty: &Type) -> String {
+    let tokens = quote!(#ty).to_string();
+    let mut string = String::with_capacity(tokens.len());
+    let mut chars = tokens.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        if !ch.is_whitespace() {
+            string.push(ch);
+            continue;
+        }
+
+        while chars.next_if(|ch| ch.is_whitespace()).is_some() {}
+
+        if !joins(string.chars().last(), chars.peek().copied()) {
+            string.push(' ');
+        }
+    }
+
+    string
+}
+
+// Returns `true` if the characters around whitespace in a rendered type are written without space
+// in between, e.g. in `&'a T`, `Vec<u8>`, `std::vec::Vec` or `Fn(u8)`.
+fn joins(prev: Option<char>, next: Option<char>) -> bool {
+    match (prev, next) {
+        (None, _) | (_, None) => true,
+        (Some('<' | '&' | '*' | '(' | '[' | ':'), _) => true,
+        (_, Some('<' | '>' | ',' | ';' | ')' | ']' | ':')) => true,
+        (Some(prev), Some('(')) => prev.is_alphanumeric() || prev == '_',
+        _ => false,
+    }
+}
+
+// Generates the `AnyFieldAccess` methods which provide entries of the `FieldTable`.
+fn expand_field_table_fns(variants: &[Variant]) -> TokenStream {
+    let table = quote!(<Self as ::field_access::__private::FieldTable>);
+
+    let field_types_arms = variants.iter().enumerate().map(|(index, variant)| {
+        let path = variant.path();
+        quote!(#path { .. } => #table::FIELD_TYPES[#index])
+    });
+
+    // The index of the first field of each variant in `FieldTable::FIELDS`.
+    let offsets: Vec<_> = variants
+        .iter()
+        .scan(0, |offset, variant| {
+            let first = *offset;
+            *offset += variant.fields().count();
+            Some(first)
+        })
+        .collect();
+
+    let field_info = lookup(
+        &Target::Any,
+        variants,
+        &quote!(::field_access::AnyFieldAccess::field_info_at(self, index)),
+        |field| {
+            let binding = &field.binding;
+            Some(quote!(::field_access::AnyFieldAccess::field_info(#binding, field)))
         },
-        Data::Enum(_) => "enums",
-        Data::Union(_) => "unions",
+        &quote!(None),
+    );
+
+    let field_info_at_arms = variants.iter().zip(&offsets).map(|(variant, offset)| {
+        let path = variant.path();
+        let end = offset + variant.fields().count();
+        quote!(#path { .. } => #table::FIELDS[#offset..#end].get(index))
+    });
+
+    quote! {
+        fn field_types(&self) -> &'static [::field_access::FieldType] {
+            match self {
+                #(#field_types_arms,)*
+            }
+        }
+
+        fn field_info(&self, field: &str) -> ::core::option::Option<&'static ::field_access::FieldInfo> {
+            #field_info
+        }
+
+        fn field_info_at(&self, index: usize) -> ::core::option::Option<&'static ::field_access::FieldInfo> {
+            match self {
+                #(#field_info_at_arms,)*
+            }
+        }
+    }
+}
+
+// Generates the `AnyFieldAccess` methods which access fields by their index in `field_names`.
+fn expand_index_fns(variants: &[Variant]) -> TokenStream {
+    let field_index = index_of(variants);
+    let field_at = index_arms(variants, false, |field| {
+        let value = Target::Any.field_value(field, false);
+        quote!(Some(#value))
+    });
+    let field_at_mut = index_arms(variants, true, |field| {
+        let value = Target::Any.field_value(field, true);
+        quote!(Some(#value))
+    });
+    let field_ref_at = index_arms(variants, false, |field| {
+        let value = field_ref_value(field, false);
+        quote!(Some(#value))
+    });
+
+    quote! {
+        fn field_index(&self, field: &str) -> ::core::option::Option<usize> {
+            #field_index
+        }
+
+        fn field_at(&self, index: usize) -> ::core::option::Option<&dyn ::core::any::Any> {
+            #field_at
+        }
+
+        fn field_at_mut(&mut self, index: usize) -> ::core::option::Option<&mut dyn ::core::any::Any> {
+            #field_at_mut
+        }
+
+        fn field_ref_at(&self, index: usize) -> ::core::option::Option<::field_access::Field<'_>> {
+            #[allow(unused_imports)]
+            use ::field_access::__private::{AsFieldAccess as _, AsFieldAccessFallback as _};
+
+            #field_ref_at
+        }
+    }
+}
+
+// Generates an expression which evaluates to the value of the field at `index` in `field_names`.
+//
+// `value` produces the expression for an accessible field from its binding. Fields which are not
+// accessible evaluate to `None`.
+fn index_arms(
+    variants: &[Variant],
+    mutable: bool,
+    value: impl Fn(&FieldSpec) -> TokenStream,
+) -> TokenStream {
+    let arms = variants.iter().map(|variant| {
+        let pattern = variant.pattern(|field| !field.flatten && field.accessible(mutable));
+        let arms = variant
+            .fields()
+            .enumerate()
+            .filter(|(_, field)| field.accessible(mutable))
+            .map(|(index, field)| {
+                let value = value(field);
+                quote!(#index => #value,)
+            });
+
+        quote!(#pattern => match index {
+            #(#arms)*
+            _ => None
+        })
+    });
+
+    quote! {
+        match self {
+            #(#arms,)*
+        }
+    }
+}
+
+// Generates an expression which looks up `field` by resolving it to its index and evaluating
+// `by_index`.
+//
+// Unknown names are forwarded to flattened fields, see `forward_flattened`. Names of fields which
+// are not accessible are not forwarded since they shadow the fields of flattened structs.
+fn lookup(
+    target: &Target,
+    variants: &[Variant],
+    by_index: &TokenStream,
+    forward: impl Fn(&FieldSpec) -> Option<TokenStream>,
+    not_found: &TokenStream,
+) -> TokenStream {
+    let has_flattened = variants
+        .iter()
+        .flat_map(Variant::flattened)
+        .next()
+        .is_some();
+
+    let forwarded = if has_flattened {
+        let arms = variants.iter().map(|variant| {
+            let pattern = variant.pattern(|field| {
+                field.flatten && (forward(field).is_some() || variant.shadows(field))
+            });
+            let flattened = forward_flattened(variant, &forward, not_found);
+
+            quote!(#pattern => #flattened)
+        });
+
+        quote! {
+            match self {
+                #(#arms,)*
+            }
+        }
+    } else {
+        not_found.clone()
     };
+    let field_index = target.field_index(variants);
+
+    quote! {
+        match #field_index {
+            Some(index) => #by_index,
+            None => #forwarded,
+        }
+    }
+}
+
+// Generates an expression which resolves the looked up name `field` to the index of the field of
+// the active variant with that name or alias.
+fn index_of(variants: &[Variant]) -> TokenStream {
+    let arms = variants.iter().map(|variant| {
+        let path = variant.path();
+        let names = variant
+            .fields()
+            .map(|field| field.names().collect())
+            .collect();
+        let dispatch = match_names(names);
+
+        quote!(#path { .. } => #dispatch)
+    });
+
+    quote! {
+        match self {
+            #(#arms,)*
+        }
+    }
+}
+
+// Generates an expression which forwards a lookup of `field` to the flattened fields of a variant.
+// `forward` produces the result of the lookup in a flattened field, or `None` if the field is not
+// accessible, in which case `not_found` is returned.
+//
+// The lookup is answered by the first flattened struct which has a field of that name, so that a
+// name always refers to the same field regardless of whether it is accessible. Only flattened
+// structs before the last one need to be checked for the name. The check resolves the name via
+// `AnyFieldAccess::field_index` of the flattened struct, which is a single dispatch for derived
+// types.
+fn forward_flattened(
+    variant: &Variant,
+    forward: impl Fn(&FieldSpec) -> Option<TokenStream>,
+    not_found: &TokenStream,
+) -> TokenStream {
+    let result = |field| forward(field).unwrap_or_else(|| not_found.clone());
+    let shadowing = variant
+        .flattened()
+        .filter(|field| variant.shadows(field))
+        .map(|field| {
+            let binding = &field.binding;
+            let result = result(field);
+
+            quote! {
+                if ::field_access::__private::is_field_name(#binding, field) {
+                    return #result;
+                }
+            }
+        });
+    let last = variant
+        .flattened()
+        .last()
+        .map_or_else(|| not_found.clone(), result);
+
+    quote! {{
+        #(#shadowing)*
+        #last
+    }}
+}
+
+// Variants with more field names than this use a dispatch on the length and bytes of the looked
+// up name instead of a linear `match`, see `match_names`.
+const DISPATCH_THRESHOLD: usize = 16;
+
+// The maximum number of names which are compared against the looked up name one after another.
+const DISPATCH_BUCKET_SIZE: usize = 4;
+
+// Generates an expression which resolves the looked up name `field` to the position of the entry of
+// `names` containing it, or to `None` if there is none.
+//
+// A `match` on strings compares `field` against each name in turn, which is slow for structs with
+// many fields. Above `DISPATCH_THRESHOLD` names, the dispatch therefore branches on the length of
+// `field` and then on the bytes which best discriminate the remaining names. Only a few names of
+// the same length are compared in the end.
+fn match_names(names: Vec<Vec<&String>>) -> TokenStream {
+    let len = names.iter().map(Vec::len).sum::<usize>();
+
+    if len <= DISPATCH_THRESHOLD {
+        let arms = names
+            .iter()
+            .enumerate()
+            .map(|(position, names)| quote!(#(#names)|* => Some(#position),));
+
+        return quote! {
+            match field {
+                #(#arms)*
+                _ => None,
+            }
+        };
+    }
+
+    let mut by_len: BTreeMap<usize, Vec<(&str, usize)>> = BTreeMap::new();
 
-    Err(Error::new_spanned(
-        input,
-        format!("FieldAccess does not support {unsupported}"),
-    ))
+    for (position, names) in names.iter().enumerate() {
+        for name in names {
+            by_len
+                .entry(name.len())
+                .or_default()
+                .push((name.as_str(), position));
+        }
+    }
+
+    let len_arms = by_len.into_iter().map(|(len, names)| {
+        let dispatch = dispatch_bytes(names);
+        quote!(#len => #dispatch,)
+    });
+
+    quote! {
+        match field.len() {
+            #(#len_arms)*
+            _ => None,
+        }
+    }
+}
+
+// Generates an expression which resolves `field` to the position of its entry in the names passed
+// to `match_names`, given that it has the same length as all `names`.
+fn dispatch_bytes(names: Vec<(&str, usize)>) -> TokenStream {
+    if names.len() <= DISPATCH_BUCKET_SIZE {
+        let arms = names
+            .iter()
+            .map(|(name, position)| quote!(#name => Some(#position),));
+
+        return quote! {
+            match field {
+                #(#arms)*
+                _ => None,
+            }
+        };
+    }
+
+    // Branch on the byte with the most distinct values. Names are unique, so there is at least one
+    // byte with two or more distinct values.
+    let index = (0..names[0].0.len())
+        .max_by_key(|&index| {
+            let mut bytes: Vec<u8> = names
+                .iter()
+                .map(|(name, _)| name.as_bytes()[index])
+                .collect();
+            bytes.sort_unstable();
+            bytes.dedup();
+            // Prefer the first of several equally good bytes.
+            (bytes.len(), Reverse(index))
+        })
+        .unwrap_or_default();
+
+    let mut by_byte: BTreeMap<u8, Vec<(&str, usize)>> = BTreeMap::new();
+
+    for (name, position) in names {
+        by_byte
+            .entry(name.as_bytes()[index])
+            .or_default()
+            .push((name, position));
+    }
+
+    let arms = by_byte.into_iter().map(|(byte, names)| {
+        let byte = Literal::u8_suffixed(byte);
+        let dispatch = dispatch_bytes(names);
+        quote!(#byte => #dispatch,)
+    });
+
+    quote! {
+        match field.as_bytes()[#index] {
+            #(#arms)*
+            _ => None,
+        }
+    }
+}
+
+/// A pair of `AnyFieldAccess` methods which provide fields as trait objects.
+///
+/// Whether the type of a field can be converted into the trait object is detected via autoref
+/// specialization, see `field_access::__private::Wrap`.
+struct DynLookup {
+    method: &'static str,
+    object: TokenStream,
+    // The autoref specialization traits in order of decreasing priority.
+    traits: &'static [&'static str],
+    // The method of the autoref specialization traits which provides the conversion.
+    convert: &'static str,
+}
+
+impl DynLookup {
+    fn field_access() -> DynLookup {
+        DynLookup {
+            method: "field_as_field_access",
+            object: quote!(::field_access::AnyFieldAccess),
+            traits: &["AsFieldAccess", "AsFieldAccessFallback"],
+            convert: "field_access",
+        }
+    }
+
+    fn sequence() -> DynLookup {
+        DynLookup {
+            method: "field_as_sequence",
+            object: quote!(::field_access::AnySequence),
+            traits: &["AsAccessSequence", "AsAnySequence", "AsSequenceFallback"],
+            convert: "sequence",
+        }
+    }
+
+    fn expand(&self, variants: &[Variant]) -> TokenStream {
+        let object = &self.object;
+        let method = Ident::new(self.method, Span::call_site());
+        let method_mut = Ident::new(&format!("{}_mut", self.method), Span::call_site());
+        let traits: Vec<_> = self
+            .traits
+            .iter()
+            .map(|name| Ident::new(name, Span::call_site()))
+            .collect();
+        let fn_body = |mutable: bool| {
+            let (forward, convert) = if mutable {
+                (&method_mut, format!("{}_mut_fn", self.convert))
+            } else {
+                (&method, format!("{}_fn", self.convert))
+            };
+            let convert = Ident::new(&convert, Span::call_site());
+            // Higher priority implementations are selected by adding more references.
+            let refs = iter::repeat(quote!(&)).take(traits.len() - 1);
+            let by_index = index_arms(variants, mutable, |field| {
+                let binding = &field.binding;
+                let refs = refs.clone();
+                quote! {
+                    (#(#refs)* ::field_access::__private::Wrap::new(#binding))
+                        .#convert()
+                        .map(|convert| convert(#binding))
+                }
+            });
+            let lookup = lookup(
+                &Target::Any,
+                variants,
+                &by_index,
+                |field| {
+                    let binding = &field.binding;
+                    field
+                        .accessible(mutable)
+                        .then(|| quote!(::field_access::AnyFieldAccess::#forward(#binding, field)))
+                },
+                &quote!(None),
+            );
+
+            quote! {
+                #[allow(unused_imports)]
+                use ::field_access::__private::{#(#traits as _),*};
+
+                #lookup
+            }
+        };
+        let body = fn_body(false);
+        let body_mut = fn_body(true);
+
+        quote! {
+            fn #method(&self, field: &str) -> ::core::option::Option<&dyn #object> {
+                #body
+            }
+
+            fn #method_mut(&mut self, field: &str) -> ::core::option::Option<&mut dyn #object> {
+                #body_mut
+            }
+        }
+    }
 }
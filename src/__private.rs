@@ -0,0 +1,246 @@
+//! Implementation details of the derive macro. Not public API.
+
+use crate::sequence::{AccessElements, Elements, Slice};
+use crate::{AnyFieldAccess, AnySequence, Field, FieldInfo, FieldMut, FieldType};
+use core::any::{self, Any};
+use core::marker::PhantomData;
+
+/// Provides `&dyn Any` views of `AnyFieldAccess` trait objects.
+///
+/// This is a supertrait of [`AnyFieldAccess`] with a blanket implementation for all types since
+/// trait objects cannot be upcasted on older Rust versions.
+pub trait AsDynAny {
+    fn as_dyn_any(&self) -> &dyn Any;
+
+    fn as_dyn_any_mut(&mut self) -> &mut dyn Any;
+
+    fn dyn_type_name(&self) -> &'static str;
+}
+
+impl<T: Any> AsDynAny for T {
+    #[inline]
+    fn as_dyn_any(&self) -> &dyn Any {
+        self
+    }
+
+    #[inline]
+    fn as_dyn_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    #[inline]
+    fn dyn_type_name(&self) -> &'static str {
+        any::type_name::<T>()
+    }
+}
+
+/// Static field information generated by the derive macro.
+///
+/// This is an associated constant of a trait instead of a `static` so that it can depend on the
+/// type parameters of the implementing type.
+pub trait FieldTable {
+    /// The field types of each enum variant in declaration order. Structs have a single entry.
+    const FIELD_TYPES: &'static [&'static [FieldType]];
+
+    /// The fields of all enum variants in declaration order.
+    const FIELDS: &'static [FieldInfo];
+}
+
+/// A marker for the type of a field which is used to select conversions of the field into trait
+/// objects via autoref specialization.
+///
+/// The traits below are implemented for `Wrap<T>` with an increasing number of references in order
+/// of their priority. Calling e.g. `(&Wrap::new(&field)).field_access_fn()` resolves to
+/// [`AsFieldAccess`] if the field type implements [`AnyFieldAccess`] and to
+/// [`AsFieldAccessFallback`] otherwise.
+///
+/// The conversions are returned as function pointers so that they can be applied to mutable
+/// references without moving them into the marker.
+pub struct Wrap<T: ?Sized>(PhantomData<T>);
+
+impl<T: ?Sized> Wrap<T> {
+    #[inline]
+    pub fn new(_: &T) -> Self {
+        Wrap(PhantomData)
+    }
+}
+
+// Lower priority implementations take `Wrap<T>` by value through a reference, so it needs to be
+// `Copy` regardless of `T`.
+impl<T: ?Sized> Clone for Wrap<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for Wrap<T> {}
+
+fn field_access<T: AnyFieldAccess>(value: &T) -> &dyn AnyFieldAccess {
+    value
+}
+
+fn field_access_mut<T: AnyFieldAccess>(value: &mut T) -> &mut dyn AnyFieldAccess {
+    value
+}
+
+/// Creates the `Field` returned by the generated `AnyFieldAccess::field_ref`.
+///
+/// `access` is the conversion selected via [`AsFieldAccess`] and [`AsFieldAccessFallback`].
+#[inline]
+pub fn field<T: Any>(value: &T, access: Option<fn(&T) -> &dyn AnyFieldAccess>) -> Field<'_> {
+    match access {
+        Some(access) => Field::from_access(access(value)),
+        None => Field::from_value(value),
+    }
+}
+
+/// Creates the `FieldMut` returned by the generated `AnyFieldAccess::field_ref_mut`.
+#[inline]
+pub fn field_mut<T: Any>(
+    value: &mut T,
+    access: Option<fn(&mut T) -> &mut dyn AnyFieldAccess>,
+) -> FieldMut<'_> {
+    match access {
+        Some(access) => FieldMut::from_access(access(value)),
+        None => FieldMut::from_value(value),
+    }
+}
+
+/// Returns `true` if `name` is the name or an alias of a field of `access` or one of its flattened
+/// structs.
+///
+/// Used by the generated lookups to stop forwarding names which are shadowed by a flattened struct.
+#[inline]
+pub fn is_field_name(access: &dyn AnyFieldAccess, name: &str) -> bool {
+    crate::is_field_name(access, name)
+}
+
+pub trait AsFieldAccess<T: ?Sized> {
+    fn field_access_fn(self) -> Option<fn(&T) -> &dyn AnyFieldAccess>;
+
+    fn field_access_mut_fn(self) -> Option<fn(&mut T) -> &mut dyn AnyFieldAccess>;
+}
+
+impl<T: AnyFieldAccess> AsFieldAccess<T> for &Wrap<T> {
+    #[inline]
+    fn field_access_fn(self) -> Option<fn(&T) -> &dyn AnyFieldAccess> {
+        Some(field_access)
+    }
+
+    #[inline]
+    fn field_access_mut_fn(self) -> Option<fn(&mut T) -> &mut dyn AnyFieldAccess> {
+        Some(field_access_mut)
+    }
+}
+
+pub trait AsFieldAccessFallback<T: ?Sized> {
+    fn field_access_fn(self) -> Option<fn(&T) -> &dyn AnyFieldAccess>;
+
+    fn field_access_mut_fn(self) -> Option<fn(&mut T) -> &mut dyn AnyFieldAccess>;
+}
+
+impl<T: ?Sized> AsFieldAccessFallback<T> for Wrap<T> {
+    #[inline]
+    fn field_access_fn(self) -> Option<fn(&T) -> &dyn AnyFieldAccess> {
+        None
+    }
+
+    #[inline]
+    fn field_access_mut_fn(self) -> Option<fn(&mut T) -> &mut dyn AnyFieldAccess> {
+        None
+    }
+}
+
+fn access_elements<S>(value: &S) -> &dyn AnySequence
+where
+    S: Slice,
+    S::Item: AnyFieldAccess,
+{
+    AccessElements::new(value)
+}
+
+fn access_elements_mut<S>(value: &mut S) -> &mut dyn AnySequence
+where
+    S: Slice,
+    S::Item: AnyFieldAccess,
+{
+    AccessElements::new_mut(value)
+}
+
+fn elements<S>(value: &S) -> &dyn AnySequence
+where
+    S: Slice,
+    S::Item: Any,
+{
+    Elements::new(value)
+}
+
+fn elements_mut<S>(value: &mut S) -> &mut dyn AnySequence
+where
+    S: Slice,
+    S::Item: Any,
+{
+    Elements::new_mut(value)
+}
+
+pub trait AsAccessSequence<T: ?Sized> {
+    fn sequence_fn(self) -> Option<fn(&T) -> &dyn AnySequence>;
+
+    fn sequence_mut_fn(self) -> Option<fn(&mut T) -> &mut dyn AnySequence>;
+}
+
+impl<S> AsAccessSequence<S> for &&Wrap<S>
+where
+    S: Slice,
+    S::Item: AnyFieldAccess,
+{
+    #[inline]
+    fn sequence_fn(self) -> Option<fn(&S) -> &dyn AnySequence> {
+        Some(access_elements)
+    }
+
+    #[inline]
+    fn sequence_mut_fn(self) -> Option<fn(&mut S) -> &mut dyn AnySequence> {
+        Some(access_elements_mut)
+    }
+}
+
+pub trait AsAnySequence<T: ?Sized> {
+    fn sequence_fn(self) -> Option<fn(&T) -> &dyn AnySequence>;
+
+    fn sequence_mut_fn(self) -> Option<fn(&mut T) -> &mut dyn AnySequence>;
+}
+
+impl<S> AsAnySequence<S> for &Wrap<S>
+where
+    S: Slice,
+    S::Item: Any,
+{
+    #[inline]
+    fn sequence_fn(self) -> Option<fn(&S) -> &dyn AnySequence> {
+        Some(elements)
+    }
+
+    #[inline]
+    fn sequence_mut_fn(self) -> Option<fn(&mut S) -> &mut dyn AnySequence> {
+        Some(elements_mut)
+    }
+}
+
+pub trait AsSequenceFallback<T: ?Sized> {
+    fn sequence_fn(self) -> Option<fn(&T) -> &dyn AnySequence>;
+
+    fn sequence_mut_fn(self) -> Option<fn(&mut T) -> &mut dyn AnySequence>;
+}
+
+impl<T: ?Sized> AsSequenceFallback<T> for Wrap<T> {
+    #[inline]
+    fn sequence_fn(self) -> Option<fn(&T) -> &dyn AnySequence> {
+        None
+    }
+
+    #[inline]
+    fn sequence_mut_fn(self) -> Option<fn(&mut T) -> &mut dyn AnySequence> {
+        None
+    }
+}
@@ -0,0 +1,292 @@
+//! Field access for structs with non-`'static` lifetimes.
+//!
+//! [`AnyFieldAccess`](crate::AnyFieldAccess) is built on top of [`Any`] and thus requires
+//! `Self: 'static`. The types in this module lift that restriction for structs that borrow data
+//! via a single lifetime parameter, e.g. the output of zero-copy parsers.
+
+use core::any::Any;
+use core::marker::PhantomData;
+use core::mem;
+
+/// Struct field access for types with a lifetime parameter.
+///
+/// This is the counterpart of [`FieldAccess`](crate::FieldAccess) for structs and enums which
+/// borrow data for the lifetime `'a`. Fields of type `&'a T` (where `T: 'static`) can be obtained
+/// for the full lifetime `'a`, all other fields must be `'static`.
+///
+/// `#[derive(FieldAccess)]` implements this trait instead of
+/// [`AnyFieldAccess`](crate::AnyFieldAccess) for types with exactly one lifetime parameter.
+///
+/// # Example
+///
+/// ```
+/// use field_access::{BorrowedFieldAccess, FieldAccess};
+///
+/// #[derive(FieldAccess)]
+/// struct Message<'a> {
+///     id: u32,
+///     payload: &'a [u8],
+/// }
+///
+/// let buf = vec![1, 2, 3];
+/// let message = Message { id: 1, payload: &buf };
+///
+/// let payload: &[u8] = message.field("payload").unwrap().as_slice().unwrap();
+///
+/// assert_eq!(message.field("id").unwrap().get::<u32>(), Some(&1));
+/// assert_eq!(payload, &[1, 2, 3]);
+/// ```
+pub trait BorrowedFieldAccess<'a> {
+    /// Immutable field access.
+    ///
+    /// Returns `Some(_)` if the field is accessible, otherwise `None`.
+    fn field(&self, field: &str) -> Option<BorrowedField<'_, 'a>>;
+
+    /// Mutable field access.
+    ///
+    /// Returns `Some(_)` if the field is accessible, otherwise `None`.
+    fn field_mut(&mut self, field: &str) -> Option<BorrowedFieldMut<'_, 'a>>;
+
+    /// Provides the names of all accessible fields.
+    ///
+    /// The field name order is undefined and should not be relied upon.
+    fn field_names(&self) -> &'static [&'static str];
+
+    /// Provides the aliases of a field.
+    ///
+    /// Aliases are alternative names which can be used to look up a field. They are not included
+    /// in [`.field_names()`](Self::field_names). Returns an empty slice if the field has no
+    /// aliases or does not exist.
+    #[inline]
+    fn field_aliases(&self, field: &str) -> &'static [&'static str] {
+        let _ = field;
+        &[]
+    }
+
+    /// Provides the name of the active enum variant.
+    ///
+    /// Returns `Some(_)` if `Self` is an enum, otherwise `None`.
+    #[inline]
+    fn variant_name(&self) -> Option<&'static str> {
+        None
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Value<'f> {
+    // A `'static` value.
+    Owned(&'f dyn Any),
+    // A `&'a T` whose lifetime was erased to `&'static T`. This is never exposed as such.
+    Borrowed(&'f dyn Any),
+}
+
+/// An immutable reference to a field of a struct implementing [`BorrowedFieldAccess`].
+///
+/// The lifetime `'f` is the lifetime of the borrow of the struct, while `'a` is the lifetime of
+/// the data borrowed by the struct.
+#[derive(Debug, Clone)]
+pub struct BorrowedField<'f, 'a> {
+    value: Value<'f>,
+    marker: PhantomData<&'a ()>,
+}
+
+impl<'f, 'a: 'f> BorrowedField<'f, 'a> {
+    /// Creates a `BorrowedField` from a reference to a `'static` value.
+    pub fn owned<T: Any>(value: &'f T) -> Self {
+        BorrowedField {
+            value: Value::Owned(value),
+            marker: PhantomData,
+        }
+    }
+
+    /// Creates a `BorrowedField` from a reference to a field of type `&'a T`.
+    pub fn borrowed<T: ?Sized + 'static>(value: &'f &'a T) -> Self {
+        // SAFETY: `&'a T` and `&'static T` only differ in their lifetime. The erased value is only
+        // ever handed out as `&'a T` again.
+        let value = unsafe { &*(value as *const &'a T).cast::<&'static T>() };
+
+        BorrowedField {
+            value: Value::Borrowed(value),
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns `true` if the field is of type `T`.
+    ///
+    /// This only considers `'static` fields. Use [`.is_ref::<T>()`](Self::is_ref) for fields of
+    /// type `&'a T`.
+    #[inline]
+    pub fn is<T: Any>(&self) -> bool {
+        matches!(self.value, Value::Owned(value) if value.is::<T>())
+    }
+
+    /// Obtains an immutable reference to the value of type `T`.
+    ///
+    /// Returns `Some(_)` if the field is a `'static` value of type `T`, `None` otherwise.
+    #[inline]
+    pub fn get<T: Any>(&self) -> Option<&'f T> {
+        match self.value {
+            Value::Owned(value) => value.downcast_ref(),
+            Value::Borrowed(_) => None,
+        }
+    }
+
+    /// Returns `true` if the field is of type `&'a T`.
+    #[inline]
+    pub fn is_ref<T: ?Sized + 'static>(&self) -> bool {
+        matches!(self.value, Value::Borrowed(value) if value.is::<&'static T>())
+    }
+
+    /// Obtains the reference stored in a field of type `&'a T`.
+    ///
+    /// In contrast to [`.get::<T>()`](Self::get), the returned reference is valid for the lifetime
+    /// of the borrowed data and not only for the borrow of the struct.
+    ///
+    /// Returns `Some(_)` if the field is of type `&'a T`, `None` otherwise.
+    #[inline]
+    pub fn get_ref<T: ?Sized + 'static>(&self) -> Option<&'a T> {
+        match self.value {
+            Value::Borrowed(value) => value.downcast_ref::<&'static T>().map(|&v| v as &'a T),
+            Value::Owned(_) => None,
+        }
+    }
+
+    /// Obtains the value of a field of type `&'a str`.
+    ///
+    /// Returns `Some(_)` if [`.is_ref::<str>()`](Self::is_ref) would return `true`, `None`
+    /// otherwise.
+    #[inline]
+    pub fn as_str(&self) -> Option<&'a str> {
+        self.get_ref()
+    }
+
+    /// Obtains the value of a field of type `&'a [T]`.
+    ///
+    /// Returns `Some(_)` if [`.is_ref::<[T]>()`](Self::is_ref) would return `true`, `None`
+    /// otherwise.
+    #[inline]
+    pub fn as_slice<T: 'static>(&self) -> Option<&'a [T]> {
+        self.get_ref()
+    }
+}
+
+#[derive(Debug)]
+enum ValueMut<'f> {
+    Owned(&'f mut dyn Any),
+    Borrowed(&'f mut dyn Any),
+}
+
+/// A mutable reference to a field of a struct implementing [`BorrowedFieldAccess`].
+///
+/// The lifetime `'f` is the lifetime of the borrow of the struct, while `'a` is the lifetime of
+/// the data borrowed by the struct.
+#[derive(Debug)]
+pub struct BorrowedFieldMut<'f, 'a> {
+    value: ValueMut<'f>,
+    // Invariant over `'a`, otherwise it would be possible to store references with a shorter
+    // lifetime in the field.
+    marker: PhantomData<fn(&'a ()) -> &'a ()>,
+}
+
+impl<'f, 'a: 'f> BorrowedFieldMut<'f, 'a> {
+    /// Creates a `BorrowedFieldMut` from a mutable reference to a `'static` value.
+    pub fn owned<T: Any>(value: &'f mut T) -> Self {
+        BorrowedFieldMut {
+            value: ValueMut::Owned(value),
+            marker: PhantomData,
+        }
+    }
+
+    /// Creates a `BorrowedFieldMut` from a mutable reference to a field of type `&'a T`.
+    pub fn borrowed<T: ?Sized + 'static>(value: &'f mut &'a T) -> Self {
+        // SAFETY: `&'a T` and `&'static T` only differ in their lifetime. The erased value is only
+        // ever handed out as `&'a T` again and only `&'a T` values are written to it.
+        let value = unsafe { &mut *(value as *mut &'a T).cast::<&'static T>() };
+
+        BorrowedFieldMut {
+            value: ValueMut::Borrowed(value),
+            marker: PhantomData,
+        }
+    }
+
+    /// Obtains a mutable reference to the value of type `T`.
+    ///
+    /// Returns `Some(_)` if the field is a `'static` value of type `T`, `None` otherwise.
+    #[inline]
+    pub fn get_mut<T: Any>(&mut self) -> Option<&mut T> {
+        match &mut self.value {
+            ValueMut::Owned(value) => value.downcast_mut(),
+            ValueMut::Borrowed(_) => None,
+        }
+    }
+
+    /// Obtains the reference stored in a field of type `&'a T`.
+    ///
+    /// Returns `Some(_)` if the field is of type `&'a T`, `None` otherwise.
+    #[inline]
+    pub fn get_ref<T: ?Sized + 'static>(&self) -> Option<&'a T> {
+        match &self.value {
+            ValueMut::Borrowed(value) => value.downcast_ref::<&'static T>().map(|&v| v as &'a T),
+            ValueMut::Owned(_) => None,
+        }
+    }
+
+    /// Sets the value of a `'static` field.
+    ///
+    /// Returns `true` if it was possible to replace the field's value with a value of type `T`,
+    /// `false` otherwise.
+    #[inline]
+    pub fn set<T: Any>(&mut self, value: T) -> bool {
+        self.replace(value).is_some()
+    }
+
+    /// Replaces the value of a `'static` field, returning the previous value.
+    ///
+    /// Returns `Some(old_value)` if it was possible to replace the field's value with a value of
+    /// type `T`, `None` otherwise.
+    #[inline]
+    pub fn replace<T: Any>(&mut self, value: T) -> Option<T> {
+        self.get_mut().map(|dest| mem::replace(dest, value))
+    }
+
+    /// Sets the value of a field of type `&'a T`.
+    ///
+    /// Returns `true` if the field is of type `&'a T`, `false` otherwise.
+    #[inline]
+    pub fn set_ref<T: ?Sized + 'static>(&mut self, value: &'a T) -> bool {
+        self.replace_ref(value).is_some()
+    }
+
+    /// Replaces the value of a field of type `&'a T`, returning the previous value.
+    ///
+    /// Returns `Some(old_value)` if the field is of type `&'a T`, `None` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use field_access::{BorrowedFieldAccess, FieldAccess};
+    ///
+    /// #[derive(FieldAccess)]
+    /// struct Token<'a> {
+    ///     text: &'a str,
+    /// }
+    ///
+    /// let input = String::from("foo bar");
+    /// let mut token = Token { text: &input[..3] };
+    /// let mut field = token.field_mut("text").unwrap();
+    ///
+    /// assert_eq!(field.replace_ref(&input[4..]), Some("foo"));
+    /// assert_eq!(token.text, "bar");
+    /// ```
+    #[inline]
+    pub fn replace_ref<T: ?Sized + 'static>(&mut self, value: &'a T) -> Option<&'a T> {
+        match &mut self.value {
+            ValueMut::Borrowed(dest) => dest.downcast_mut::<&'static T>().map(|dest| {
+                // SAFETY: `dest` actually is a `&'a T`, so storing a `&'a T` in it is fine.
+                let value: &'static T = unsafe { &*(value as *const T) };
+                mem::replace(dest, value) as &'a T
+            }),
+            ValueMut::Owned(_) => None,
+        }
+    }
+}
@@ -0,0 +1,111 @@
+//! Errors returned by fallible field operations.
+
+use crate::Field;
+use core::any::{self, TypeId};
+use core::fmt;
+
+/// An error returned by the `try_`-prefixed field operations.
+///
+/// With the `std` feature, this type implements `std::error::Error`. There is no `Error`
+/// implementation in `no_std` builds.
+///
+/// # Example
+///
+/// ```
+/// use field_access::{FieldAccess, FieldError};
+///
+/// #[derive(FieldAccess)]
+/// struct Foo {
+///     #[field_access(readonly)]
+///     id: u32,
+///     port: u16,
+/// }
+///
+/// let mut foo = Foo { id: 1, port: 80 };
+///
+/// let err = foo.try_field("prot").unwrap_err();
+///
+/// assert_eq!(err, FieldError::NoSuchField { name: "prot", suggestion: Some("port") });
+/// assert_eq!(err.to_string(), "no such field `prot`, did you mean `port`?");
+/// assert_eq!(foo.try_field_mut("id").unwrap_err(), FieldError::ReadOnly { name: "id" });
+///
+/// let err = foo.try_field_mut("port").unwrap().try_set(443u32).unwrap_err();
+///
+/// assert_eq!(err.to_string(), "type mismatch: expected a value of type `u32`, found `u16`");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FieldError<'a> {
+    /// The field does not exist or is not accessible.
+    NoSuchField {
+        /// The name of the field.
+        name: &'a str,
+        /// The name of an existing field which is similar to `name`, if any.
+        ///
+        /// See [`FieldAccess::suggest_field`](crate::FieldAccess::suggest_field).
+        suggestion: Option<&'static str>,
+    },
+    /// The field was accessed mutably but is only readable.
+    ReadOnly {
+        /// The name of the field.
+        name: &'a str,
+    },
+    /// The field was accessed immutably but is only writable.
+    WriteOnly {
+        /// The name of the field.
+        name: &'a str,
+    },
+    /// The field's value is not of the requested type.
+    TypeMismatch {
+        /// The name of the requested type as returned by [`core::any::type_name`].
+        expected: &'static str,
+        /// The `TypeId` of the field's value.
+        actual: TypeId,
+        /// The name of the type of the field's value if it is known, see [`Field::type_name`].
+        actual_name: Option<&'static str>,
+    },
+}
+
+impl FieldError<'_> {
+    pub(crate) fn type_mismatch<T: ?Sized>(field: &Field<'_>) -> Self {
+        FieldError::TypeMismatch {
+            expected: any::type_name::<T>(),
+            actual: field.type_id(),
+            actual_name: field.type_name(),
+        }
+    }
+}
+
+impl fmt::Display for FieldError<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FieldError::NoSuchField { name, suggestion } => {
+                write!(f, "no such field `{name}`")?;
+
+                if let Some(suggestion) = suggestion {
+                    write!(f, ", did you mean `{suggestion}`?")?;
+                }
+
+                Ok(())
+            }
+            FieldError::ReadOnly { name } => write!(f, "field `{name}` is read-only"),
+            FieldError::WriteOnly { name } => write!(f, "field `{name}` is write-only"),
+            FieldError::TypeMismatch {
+                expected,
+                actual_name,
+                ..
+            } => {
+                write!(f, "type mismatch: expected a value of type `{expected}`")?;
+
+                if let Some(actual_name) = actual_name {
+                    write!(f, ", found `{actual_name}`")?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FieldError<'_> {}
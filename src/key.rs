@@ -0,0 +1,175 @@
+//! Statically typed field keys.
+
+use core::fmt;
+use core::marker::PhantomData;
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for super::ReadWrite {}
+    impl Sealed for super::ReadOnly {}
+    impl Sealed for super::WriteOnly {}
+}
+
+/// Marker for the access mode of a [`FieldKey`] whose field can be accessed immutably and mutably.
+#[derive(Debug)]
+pub enum ReadWrite {}
+
+/// Marker for the access mode of a [`FieldKey`] for a `#[field_access(readonly)]` field.
+#[derive(Debug)]
+pub enum ReadOnly {}
+
+/// Marker for the access mode of a [`FieldKey`] for a `#[field_access(writeonly)]` field.
+#[derive(Debug)]
+pub enum WriteOnly {}
+
+/// Access modes which allow immutable access to a field.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait Readable: sealed::Sealed {}
+
+impl Readable for ReadWrite {}
+impl Readable for ReadOnly {}
+
+/// Access modes which allow mutable access to a field.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait Writable: sealed::Sealed {}
+
+impl Writable for ReadWrite {}
+impl Writable for WriteOnly {}
+
+/// A key for the field of type `T` of struct `S` which is resolved at compile time.
+///
+/// Keys are returned by associated functions which `#[derive(FieldAccess)]` generates for structs
+/// with the `#[field_access(keys)]` attribute. The functions are named after the fields with a
+/// `key_` prefix, e.g. `key_port`. For fields of tuple structs, they are named `key_0`, `key_1`
+/// and so on.
+///
+/// In contrast to the name-based lookup of [`FieldAccess::field`](crate::FieldAccess::field),
+/// accessing a field via a key can neither fail nor requires a downcast. The access mode `A`
+/// reflects the `readonly` and `writeonly` field attributes, so that e.g. mutable access to a
+/// read-only field is rejected by the compiler.
+///
+/// The name and index of the field are available via [`.name()`](Self::name) and
+/// [`.index()`](Self::index) for use with the dynamic API.
+///
+/// # Example
+///
+/// ```
+/// use field_access::{AnyFieldAccess, FieldAccess, FieldKey, ReadOnly};
+///
+/// #[derive(FieldAccess)]
+/// #[field_access(keys)]
+/// struct Server {
+///     #[field_access(readonly)]
+///     id: u64,
+///     port: u16,
+/// }
+///
+/// let mut server = Server { id: 1, port: 80 };
+///
+/// let id: FieldKey<Server, u64, ReadOnly> = Server::key_id();
+/// assert_eq!(server.get_key(id), &1);
+///
+/// *server.get_key_mut(Server::key_port()) = 443;
+/// assert_eq!(server.port, 443);
+///
+/// let port = Server::key_port();
+/// assert_eq!(port.name(), "port");
+/// assert_eq!(server.field_at(port.index()).unwrap().downcast_ref(), Some(&443u16));
+/// ```
+///
+/// Keys of read-only fields cannot be used for mutable access:
+///
+/// ```compile_fail
+/// use field_access::FieldAccess;
+///
+/// #[derive(FieldAccess)]
+/// #[field_access(keys)]
+/// struct Server {
+///     #[field_access(readonly)]
+///     id: u64,
+/// }
+///
+/// let mut server = Server { id: 1 };
+///
+/// *server.get_key_mut(Server::key_id()) = 2;
+/// ```
+pub struct FieldKey<S, T, A = ReadWrite> {
+    name: &'static str,
+    index: usize,
+    get: fn(&S) -> &T,
+    get_mut: fn(&mut S) -> &mut T,
+    access: PhantomData<fn() -> A>,
+}
+
+impl<S, T, A> FieldKey<S, T, A> {
+    #[doc(hidden)]
+    pub fn new(
+        name: &'static str,
+        index: usize,
+        get: fn(&S) -> &T,
+        get_mut: fn(&mut S) -> &mut T,
+    ) -> Self {
+        FieldKey {
+            name,
+            index,
+            get,
+            get_mut,
+            access: PhantomData,
+        }
+    }
+
+    /// Returns the name of the field.
+    ///
+    /// This is the name under which the field is accessible via
+    /// [`FieldAccess::field`](crate::FieldAccess::field), i.e. `rename` attributes are applied.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the index of the field in
+    /// [`AnyFieldAccess::field_names`](crate::AnyFieldAccess::field_names).
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns a reference to the field of `value`.
+    ///
+    /// See also [`FieldAccess::get_key`](crate::FieldAccess::get_key).
+    pub fn get<'a>(&self, value: &'a S) -> &'a T
+    where
+        A: Readable,
+    {
+        (self.get)(value)
+    }
+
+    /// Returns a mutable reference to the field of `value`.
+    ///
+    /// See also [`FieldAccess::get_key_mut`](crate::FieldAccess::get_key_mut).
+    pub fn get_mut<'a>(&self, value: &'a mut S) -> &'a mut T
+    where
+        A: Writable,
+    {
+        (self.get_mut)(value)
+    }
+}
+
+// Implemented manually since deriving would require `S: Clone`, `T: Clone` and `A: Clone`.
+impl<S, T, A> Clone for FieldKey<S, T, A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S, T, A> Copy for FieldKey<S, T, A> {}
+
+impl<S, T, A> fmt::Debug for FieldKey<S, T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FieldKey")
+            .field("name", &self.name)
+            .field("index", &self.index)
+            .finish_non_exhaustive()
+    }
+}
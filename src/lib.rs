@@ -6,23 +6,102 @@
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 #[macro_use]
 mod macros;
+#[doc(hidden)]
+pub mod __private;
+mod borrowed;
+mod error;
+mod key;
+mod path;
+mod pointer;
+mod sequence;
+mod suggest;
+mod types;
 
 #[cfg(feature = "alloc")]
 use alloc::string::String;
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 use core::any::{Any, TypeId};
+use core::cell::Cell;
 use core::fmt;
 use core::iter::FusedIterator;
 use core::mem;
 use core::ops;
-use core::slice;
 use paste::paste;
 
-/// Derive macro for automatically implementing [`AnyFieldAccess`] on structs.
+pub use borrowed::{BorrowedField, BorrowedFieldAccess, BorrowedFieldMut};
+pub use error::FieldError;
+pub use key::{FieldKey, ReadOnly, ReadWrite, Readable, Writable, WriteOnly};
+pub use path::{PathError, PathErrorKind};
+pub use sequence::AnySequence;
+pub use types::{FieldInfo, FieldType, MetaValue};
+
+/// Derive macro for automatically implementing [`AnyFieldAccess`] on structs and enums.
+///
+/// For types with a lifetime parameter, [`BorrowedFieldAccess`] is implemented instead.
+///
+/// For types without a lifetime parameter, the macro also generates an associated constant
+/// `FIELDS: &'static [FieldInfo]` with the same visibility as the type. It provides information
+/// about the fields without requiring an instance, see [`FieldInfo`]. This includes the doc
+/// comments of the fields, which are also available via [`FieldAccess::field_doc`].
+///
+/// # Container attributes
+///
+/// - `#[field_access(rename_all = "...")]`: Rename all named fields according to the given case
+///   convention. The possible values are `"lowercase"`, `"UPPERCASE"`, `"PascalCase"`,
+///   `"camelCase"`, `"snake_case"`, `"SCREAMING_SNAKE_CASE"`, `"kebab-case"` and
+///   `"SCREAMING-KEBAB-CASE"`. Fields with a `rename` attribute are not affected.
+/// - `#[field_access(keys)]`: Generate an associated function returning a [`FieldKey`] for each
+///   field, which provides statically typed access via [`FieldAccess::get_key`] and
+///   [`FieldAccess::get_key_mut`]. The functions are named after the fields with a `key_` prefix,
+///   e.g. `key_port` or `key_0`, and have the same visibility as the fields. Only supported for
+///   structs without a lifetime parameter. Flattened fields do not get a key.
+///
+/// # Field attributes
+///
+/// - `#[field_access(rename = "name")]`: Access the field by the given name instead of its Rust
+///   name.
+/// - `#[field_access(alias = "name")]`: Allow looking up the field by an alternative name. May be
+///   specified multiple times. Aliases are not included in [`AnyFieldAccess::field_names`].
+/// - `#[field_access(skip)]`: Exclude the field from dynamic field access.
+/// - `#[field_access(flatten)]`: Expose the fields of a nested struct implementing
+///   [`AnyFieldAccess`] as if they were fields of the outer struct. The nested struct itself is not
+///   accessible by name. Not supported for types with a lifetime parameter. Cannot be combined
+///   with `writeonly`. Fields of the outer struct shadow fields of the same name in flattened
+///   structs, see [`AnyFieldAccess::flattened`].
+/// - `#[field_access(readonly)]`: Only allow immutable access to the field. Mutable access returns
+///   `None`.
+/// - `#[field_access(writeonly)]`: Only allow mutable access to the field. Immutable access
+///   returns `None`.
+/// - `#[field_access(group = "name")]`: Add the field to a group. Fields of a group can be
+///   iterated via [`FieldAccess::fields_in_group`]. May be specified multiple times.
+/// - `#[field_access(meta(key = value, ...))]`: Attach metadata to the field which is available
+///   via [`FieldAccess::field_meta`]. Values are string, boolean, integer or float literals. A key
+///   without a value is equivalent to `key = true`. May be specified multiple times.
+///
+/// # Example
+///
+/// ```
+/// use field_access::{AnyFieldAccess, FieldAccess};
+///
+/// #[derive(FieldAccess)]
+/// struct Config {
+///     #[field_access(rename = "max-retries")]
+///     max_retries: u8,
+///     r#type: &'static str,
+/// }
+///
+/// let config = Config { max_retries: 3, r#type: "http" };
+///
+/// assert_eq!(config.field_names(), &["max-retries", "type"]);
+/// assert_eq!(config.field("max-retries").unwrap().as_u8(), Some(3));
+/// assert!(config.field("max_retries").is_none());
+/// ```
 #[cfg(feature = "derive")]
 pub use field_access_derive::FieldAccess;
 
@@ -33,7 +112,7 @@ pub use field_access_derive::FieldAccess;
 ///
 /// Consider automatically implementing it via `#[derive(FieldAccess)]` for structs where you need
 /// dynamic field access.
-pub trait AnyFieldAccess: Any {
+pub trait AnyFieldAccess: Any + __private::AsDynAny {
     /// Provides an immutable reference to a struct field.
     ///
     /// Returns `Some(_)` if the field is accessible, otherwise `None`.
@@ -82,10 +161,132 @@ pub trait AnyFieldAccess: Any {
     /// ```
     fn field_as_any_mut(&mut self, field: &str) -> Option<&mut dyn Any>;
 
+    /// Provides an immutable reference to a struct field which implements `AnyFieldAccess` itself.
+    ///
+    /// Returns `Some(_)` if the field is accessible and its type implements `AnyFieldAccess`,
+    /// otherwise `None`. This allows to access the fields of nested structs without knowing their
+    /// concrete type.
+    ///
+    /// The derive macro detects whether a field's type implements `AnyFieldAccess` at the point of
+    /// the derive. Fields whose type is a generic type parameter are never detected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use field_access::{AnyFieldAccess, FieldAccess};
+    ///
+    /// #[derive(FieldAccess)]
+    /// struct Tls {
+    ///     cert_path: &'static str,
+    /// }
+    ///
+    /// #[derive(FieldAccess)]
+    /// struct Server {
+    ///     tls: Tls,
+    ///     port: u16,
+    /// }
+    ///
+    /// let server = Server { tls: Tls { cert_path: "cert.pem" }, port: 443 };
+    /// let tls = server.field_as_field_access("tls").unwrap();
+    ///
+    /// assert_eq!(tls.field_names(), &["cert_path"]);
+    /// assert!(server.field_as_field_access("port").is_none());
+    /// ```
+    #[inline]
+    fn field_as_field_access(&self, field: &str) -> Option<&dyn AnyFieldAccess> {
+        let _ = field;
+        None
+    }
+
+    /// Provides a mutable reference to a struct field which implements `AnyFieldAccess` itself.
+    ///
+    /// Returns `Some(_)` if the field is accessible and its type implements `AnyFieldAccess`,
+    /// otherwise `None`. See [`.field_as_field_access()`](Self::field_as_field_access) for more.
+    #[inline]
+    fn field_as_field_access_mut(&mut self, field: &str) -> Option<&mut dyn AnyFieldAccess> {
+        let _ = field;
+        None
+    }
+
+    // Looks up a field for `FieldAccess::field`. The derive macro implements this with a single
+    // lookup of `field`, while the default implementation combines the other methods.
+    #[doc(hidden)]
+    #[inline]
+    fn field_ref(&self, field: &str) -> Option<Field<'_>> {
+        Field::lookup(self, field)
+    }
+
+    // Looks up a field for `FieldAccess::field_mut`, see `field_ref`.
+    #[doc(hidden)]
+    #[inline]
+    fn field_ref_mut(&mut self, field: &str) -> Option<FieldMut<'_>> {
+        FieldMut::lookup(self, field)
+    }
+
+    // Provides the field at `index` in `field_names` for the `Fields` iterator, see `field_ref`.
+    #[doc(hidden)]
+    #[inline]
+    fn field_ref_at(&self, index: usize) -> Option<Field<'_>> {
+        self.field_ref(self.field_names().get(index)?)
+    }
+
+    // Provides the `FieldInfo` of the field at `index` in `field_names`. The derive macro
+    // implements this without a lookup of the field name.
+    #[doc(hidden)]
+    #[inline]
+    fn field_info_at(&self, index: usize) -> Option<&'static FieldInfo> {
+        self.field_info(self.field_names().get(index)?)
+    }
+
+    /// Provides immutable access to the elements of a struct field of type `Vec<T>`, `[T; N]` or
+    /// `&[T]`.
+    ///
+    /// Returns `Some(_)` if the field is accessible and is a sequence, otherwise `None`. Like for
+    /// [`.field_as_field_access()`](Self::field_as_field_access), fields whose type is a generic
+    /// type parameter are never detected by the derive macro.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use field_access::{AnyFieldAccess, FieldAccess};
+    ///
+    /// #[derive(FieldAccess)]
+    /// struct Foo {
+    ///     a: Vec<u8>,
+    ///     b: u8,
+    /// }
+    ///
+    /// let foo = Foo { a: vec![1, 2, 3], b: 4 };
+    /// let a = foo.field_as_sequence("a").unwrap();
+    ///
+    /// assert_eq!(a.len(), 3);
+    /// assert_eq!(a.element(1).unwrap().as_u8(), Some(2));
+    /// assert!(foo.field_as_sequence("b").is_none());
+    /// ```
+    #[inline]
+    fn field_as_sequence(&self, field: &str) -> Option<&dyn AnySequence> {
+        let _ = field;
+        None
+    }
+
+    /// Provides mutable access to the elements of a struct field of type `Vec<T>`, `[T; N]` or
+    /// `&[T]`.
+    ///
+    /// Returns `Some(_)` if the field is accessible and is a sequence, otherwise `None`. See
+    /// [`.field_as_sequence()`](Self::field_as_sequence) for more.
+    #[inline]
+    fn field_as_sequence_mut(&mut self, field: &str) -> Option<&mut dyn AnySequence> {
+        let _ = field;
+        None
+    }
+
     /// Provides the names of all accessible fields.
     ///
     /// The field name order is undefined and should not be relied upon.
     ///
+    /// The names of the fields of [flattened](Self::flattened) structs are not included. Use
+    /// [`FieldAccess::all_field_names`] to obtain them as well.
+    ///
     /// # Example
     ///
     /// ```
@@ -102,6 +303,286 @@ pub trait AnyFieldAccess: Any {
     /// assert_eq!(foo.field_names(), &["a", "b"]);
     /// ```
     fn field_names(&self) -> &'static [&'static str];
+
+    /// Provides an immutable reference to the field at `index` in
+    /// [`.field_names()`](Self::field_names).
+    ///
+    /// This avoids looking up fields by name, e.g. in loops which first resolve the indices of
+    /// some fields via [`.field_index()`](Self::field_index). Fields of flattened structs are not
+    /// accessible by index.
+    ///
+    /// Returns `None` if `index` is out of bounds or the field is not readable.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use field_access::{AnyFieldAccess, FieldAccess};
+    ///
+    /// #[derive(FieldAccess)]
+    /// struct Foo {
+    ///     a: u8,
+    ///     b: bool,
+    /// }
+    ///
+    /// let foo = Foo { a: 1, b: true };
+    ///
+    /// assert_eq!(foo.field_at(1).unwrap().downcast_ref::<bool>(), Some(&true));
+    /// assert!(foo.field_at(2).is_none());
+    /// ```
+    #[inline]
+    fn field_at(&self, index: usize) -> Option<&dyn Any> {
+        self.field_as_any(self.field_names().get(index)?)
+    }
+
+    /// Provides a mutable reference to the field at `index` in
+    /// [`.field_names()`](Self::field_names).
+    ///
+    /// Returns `None` if `index` is out of bounds or the field is not writable. See
+    /// [`.field_at()`](Self::field_at) for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use field_access::{AnyFieldAccess, FieldAccess};
+    ///
+    /// #[derive(FieldAccess)]
+    /// struct Foo {
+    ///     a: u8,
+    ///     #[field_access(readonly)]
+    ///     b: bool,
+    /// }
+    ///
+    /// let mut foo = Foo { a: 1, b: true };
+    ///
+    /// *foo.field_at_mut(0).unwrap().downcast_mut::<u8>().unwrap() = 2;
+    ///
+    /// assert_eq!(foo.a, 2);
+    /// assert!(foo.field_at_mut(1).is_none());
+    /// ```
+    #[inline]
+    fn field_at_mut(&mut self, index: usize) -> Option<&mut dyn Any> {
+        let name = self.field_names().get(index)?;
+        self.field_as_any_mut(name)
+    }
+
+    /// Provides the index of a field in [`.field_names()`](Self::field_names).
+    ///
+    /// Fields can be looked up by alias. Returns `None` if the field does not exist or is a field
+    /// of a flattened struct. For enums, the index refers to the fields of the active variant.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use field_access::{AnyFieldAccess, FieldAccess};
+    ///
+    /// #[derive(FieldAccess)]
+    /// struct Foo {
+    ///     a: u8,
+    ///     #[field_access(alias = "c")]
+    ///     b: bool,
+    /// }
+    ///
+    /// let foo = Foo { a: 1, b: true };
+    ///
+    /// assert_eq!(foo.field_index("b"), Some(1));
+    /// assert_eq!(foo.field_index("c"), Some(1));
+    /// assert_eq!(foo.field_index("d"), None);
+    /// ```
+    #[inline]
+    fn field_index(&self, field: &str) -> Option<usize> {
+        let field_names = self.field_names();
+
+        field_names
+            .iter()
+            .position(|name| *name == field)
+            .or_else(|| {
+                field_names
+                    .iter()
+                    .position(|name| self.field_aliases(name).contains(&field))
+            })
+    }
+
+    /// Provides the aliases of a field.
+    ///
+    /// Aliases are alternative names which can be used to look up a field. They are not included
+    /// in [`.field_names()`](Self::field_names). Returns an empty slice if the field has no
+    /// aliases or does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use field_access::{AnyFieldAccess, FieldAccess};
+    ///
+    /// #[derive(FieldAccess)]
+    /// struct Foo {
+    ///     #[field_access(alias = "old_name")]
+    ///     name: &'static str,
+    /// }
+    ///
+    /// let foo = Foo { name: "foo" };
+    ///
+    /// assert_eq!(foo.field_aliases("name"), &["old_name"]);
+    /// assert_eq!(foo.field_names(), &["name"]);
+    /// assert!(foo.field("old_name").is_some());
+    /// ```
+    #[inline]
+    fn field_aliases(&self, field: &str) -> &'static [&'static str] {
+        let _ = field;
+        &[]
+    }
+
+    /// Provides the types of all fields in the same order as
+    /// [`.field_names()`](Self::field_names).
+    ///
+    /// The returned table is static and does not depend on the field values, except for enums
+    /// where it contains the field types of the active variant.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use field_access::{AnyFieldAccess, FieldAccess};
+    ///
+    /// #[derive(FieldAccess, Default)]
+    /// struct Foo {
+    ///     a: u8,
+    ///     b: Option<&'static str>,
+    /// }
+    ///
+    /// let foo = Foo::default();
+    /// let types: Vec<_> = foo.field_types().iter().map(|ty| (ty.name(), ty.ty())).collect();
+    ///
+    /// assert_eq!(types, [("a", "u8"), ("b", "Option<&'static str>")]);
+    /// ```
+    #[inline]
+    fn field_types(&self) -> &'static [FieldType] {
+        &[]
+    }
+
+    /// Provides the type of a field.
+    ///
+    /// In contrast to [`.field_types()`](Self::field_types), fields can be looked up by alias
+    /// and fields of flattened structs are included. Returns `None` if the field does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use field_access::{AnyFieldAccess, FieldAccess};
+    ///
+    /// #[derive(FieldAccess)]
+    /// struct Foo {
+    ///     #[field_access(alias = "b")]
+    ///     a: u8,
+    /// }
+    ///
+    /// let foo = Foo { a: 1 };
+    ///
+    /// assert_eq!(foo.field_type("a").unwrap().ty(), "u8");
+    /// assert_eq!(foo.field_type("b").unwrap().name(), "a");
+    /// assert!(foo.field_type("c").is_none());
+    /// ```
+    #[inline]
+    fn field_type(&self, field: &str) -> Option<&'static FieldType> {
+        self.field_info(field).map(FieldInfo::ty)
+    }
+
+    /// Provides static information about a field.
+    ///
+    /// Fields can be looked up by alias and fields of flattened structs are included. For enums,
+    /// only the fields of the active variant are considered. Returns `None` if the field does
+    /// not exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use field_access::{AnyFieldAccess, FieldAccess};
+    ///
+    /// #[derive(FieldAccess)]
+    /// struct Foo {
+    ///     a: u8,
+    ///     #[field_access(writeonly, alias = "c")]
+    ///     b: u16,
+    /// }
+    ///
+    /// let foo = Foo { a: 1, b: 2 };
+    /// let info = foo.field_info("c").unwrap();
+    ///
+    /// assert_eq!(info.name(), "b");
+    /// assert_eq!(info.index(), 1);
+    /// assert!(!info.is_readable());
+    /// assert!(foo.field_info("d").is_none());
+    /// ```
+    #[inline]
+    fn field_info(&self, field: &str) -> Option<&'static FieldInfo> {
+        let _ = field;
+        None
+    }
+
+    /// Provides the flattened struct at `index`.
+    ///
+    /// Fields of flattened structs can be accessed as if they were fields of `self`. Lookups of
+    /// names which are not a field of `self` are forwarded to the flattened structs in order.
+    ///
+    /// A name always refers to the first field of that name in this order, even if that field is
+    /// not accessible. Fields of the same name which come after it are shadowed: they cannot be
+    /// looked up and are skipped by [`FieldAccess::all_field_names`] and [`FieldAccess::fields`].
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use field_access::{AnyFieldAccess, FieldAccess};
+    ///
+    /// #[derive(FieldAccess)]
+    /// struct Tls {
+    ///     cert_path: &'static str,
+    /// }
+    ///
+    /// #[derive(FieldAccess)]
+    /// struct Server {
+    ///     #[field_access(flatten)]
+    ///     tls: Tls,
+    ///     port: u16,
+    /// }
+    ///
+    /// let server = Server { tls: Tls { cert_path: "cert.pem" }, port: 443 };
+    ///
+    /// assert_eq!(server.flattened(0).unwrap().field_names(), &["cert_path"]);
+    /// assert!(server.flattened(1).is_none());
+    /// assert_eq!(server.field("cert_path").unwrap().as_str(), Some("cert.pem"));
+    /// ```
+    #[inline]
+    fn flattened(&self, index: usize) -> Option<&dyn AnyFieldAccess> {
+        let _ = index;
+        None
+    }
+
+    /// Provides the name of the active enum variant.
+    ///
+    /// Returns `Some(_)` if `Self` is an enum, otherwise `None`. For enums, the fields returned by
+    /// the other methods of this trait are the fields of the active variant.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use field_access::{AnyFieldAccess, FieldAccess};
+    ///
+    /// #[derive(FieldAccess)]
+    /// enum Command {
+    ///     Move { x: i32, y: i32 },
+    ///     Quit,
+    /// }
+    ///
+    /// let command = Command::Move { x: 1, y: 2 };
+    ///
+    /// assert_eq!(command.variant_name(), Some("Move"));
+    /// assert_eq!(command.field_names(), &["x", "y"]);
+    /// assert_eq!(Command::Quit.variant_name(), Some("Quit"));
+    /// ```
+    #[inline]
+    fn variant_name(&self) -> Option<&'static str> {
+        None
+    }
 }
 
 /// High-level struct field access.
@@ -133,7 +614,7 @@ pub trait FieldAccess: AnyFieldAccess {
     /// ```
     #[inline]
     fn field(&self, field: &str) -> Option<Field<'_>> {
-        self.field_as_any(field).map(Field::new)
+        self.field_ref(field)
     }
 
     /// Mutable field access.
@@ -160,7 +641,248 @@ pub trait FieldAccess: AnyFieldAccess {
     /// ```
     #[inline]
     fn field_mut(&mut self, field: &str) -> Option<FieldMut<'_>> {
-        self.field_as_any_mut(field).map(FieldMut::new)
+        self.field_ref_mut(field)
+    }
+
+    /// Immutable field access via a statically typed key.
+    ///
+    /// In contrast to [`.field()`](Self::field), this cannot fail since the field and its type
+    /// are resolved at compile time. Keys of write-only fields are rejected by the compiler. See
+    /// [`FieldKey`] for more.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use field_access::FieldAccess;
+    ///
+    /// #[derive(FieldAccess)]
+    /// #[field_access(keys)]
+    /// struct Foo {
+    ///     a: u8
+    /// }
+    ///
+    /// let foo = Foo { a: 1 };
+    ///
+    /// assert_eq!(foo.get_key(Foo::key_a()), &1);
+    /// ```
+    #[inline]
+    fn get_key<T, A>(&self, key: FieldKey<Self, T, A>) -> &T
+    where
+        Self: Sized,
+        A: Readable,
+    {
+        key.get(self)
+    }
+
+    /// Mutable field access via a statically typed key.
+    ///
+    /// In contrast to [`.field_mut()`](Self::field_mut), this cannot fail since the field and its
+    /// type are resolved at compile time. Keys of read-only fields are rejected by the compiler.
+    /// See [`FieldKey`] for more.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use field_access::FieldAccess;
+    ///
+    /// #[derive(FieldAccess)]
+    /// #[field_access(keys)]
+    /// struct Foo {
+    ///     a: u8
+    /// }
+    ///
+    /// let mut foo = Foo { a: 1 };
+    ///
+    /// *foo.get_key_mut(Foo::key_a()) += 1;
+    ///
+    /// assert_eq!(foo.a, 2);
+    /// ```
+    #[inline]
+    fn get_key_mut<T, A>(&mut self, key: FieldKey<Self, T, A>) -> &mut T
+    where
+        Self: Sized,
+        A: Writable,
+    {
+        key.get_mut(self)
+    }
+
+    /// Returns the doc comment of a field.
+    ///
+    /// Fields can be looked up by alias and fields of flattened structs are included. Returns
+    /// `None` if the field does not exist or is not documented. See [`FieldInfo::doc`] for
+    /// details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use field_access::FieldAccess;
+    ///
+    /// #[derive(FieldAccess)]
+    /// struct Server {
+    ///     /// The port to listen on.
+    ///     ///
+    ///     /// Defaults to 8080.
+    ///     port: u16,
+    ///     host: String,
+    /// }
+    ///
+    /// let server = Server { port: 8080, host: "localhost".into() };
+    ///
+    /// assert_eq!(server.field_doc("port"), Some("The port to listen on.\n\nDefaults to 8080."));
+    /// assert_eq!(server.field_doc("host"), None);
+    /// ```
+    #[inline]
+    fn field_doc(&self, field: &str) -> Option<&'static str> {
+        self.field_info(field).and_then(FieldInfo::doc)
+    }
+
+    /// Returns the metadata of a field.
+    ///
+    /// Metadata is attached to fields via `#[field_access(meta(key = value, ...))]`, see
+    /// [`MetaValue`]. Fields can be looked up by alias and fields of flattened structs are
+    /// included. Returns an empty slice if the field does not exist or has no metadata.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use field_access::{FieldAccess, MetaValue};
+    ///
+    /// #[derive(FieldAccess)]
+    /// struct Server {
+    ///     #[field_access(meta(unit = "ms", restart))]
+    ///     timeout: u64,
+    ///     port: u16,
+    /// }
+    ///
+    /// let server = Server { timeout: 1000, port: 8080 };
+    ///
+    /// assert_eq!(
+    ///     server.field_meta("timeout"),
+    ///     &[("unit", MetaValue::Str("ms")), ("restart", MetaValue::Bool(true))]
+    /// );
+    /// assert!(server.field_meta("port").is_empty());
+    /// ```
+    #[inline]
+    fn field_meta(&self, field: &str) -> &'static [(&'static str, MetaValue)] {
+        self.field_info(field).map_or(&[], FieldInfo::meta)
+    }
+
+    /// Returns the name of the field which is closest to `name`.
+    ///
+    /// This is useful for suggesting a field name if a user misspelled it. All field names
+    /// including the names of fields of flattened structs are considered. Names which only differ
+    /// in ASCII case are preferred, otherwise the name with the smallest edit distance is returned.
+    ///
+    /// Returns `None` if no field name is reasonably close to `name`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use field_access::FieldAccess;
+    ///
+    /// #[derive(FieldAccess)]
+    /// struct Server {
+    ///     host: &'static str,
+    ///     port: u16,
+    /// }
+    ///
+    /// let server = Server { host: "localhost", port: 80 };
+    ///
+    /// assert_eq!(server.suggest_field("prot"), Some("port"));
+    /// assert_eq!(server.suggest_field("HOST"), Some("host"));
+    /// assert_eq!(server.suggest_field("timeout"), None);
+    /// ```
+    fn suggest_field(&self, name: &str) -> Option<&'static str> {
+        let candidates = self.field_names().iter().copied().chain(
+            (0..)
+                .map_while(|index| self.flattened(index))
+                .flat_map(FieldNames::new),
+        );
+
+        suggest::closest(name, candidates)
+    }
+
+    /// Immutable field access which reports why a field is not accessible.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FieldError::WriteOnly`] if the field exists but is not readable and
+    /// [`FieldError::NoSuchField`] if it does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use field_access::{FieldAccess, FieldError};
+    ///
+    /// #[derive(FieldAccess)]
+    /// struct Foo {
+    ///     a: u8,
+    ///     #[field_access(writeonly)]
+    ///     b: u8,
+    /// }
+    ///
+    /// let foo = Foo { a: 1, b: 2 };
+    ///
+    /// assert!(foo.try_field("a").is_ok());
+    /// assert_eq!(foo.try_field("b").unwrap_err(), FieldError::WriteOnly { name: "b" });
+    /// assert!(matches!(foo.try_field("c"), Err(FieldError::NoSuchField { name: "c", .. })));
+    /// ```
+    #[inline]
+    fn try_field<'n>(&self, field: &'n str) -> Result<Field<'_>, FieldError<'n>> {
+        self.field_ref(field).ok_or_else(|| {
+            if is_field_name(self, field) {
+                FieldError::WriteOnly { name: field }
+            } else {
+                FieldError::NoSuchField {
+                    name: field,
+                    suggestion: self.suggest_field(field),
+                }
+            }
+        })
+    }
+
+    /// Mutable field access which reports why a field is not accessible.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FieldError::ReadOnly`] if the field exists but is not writable and
+    /// [`FieldError::NoSuchField`] if it does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use field_access::{FieldAccess, FieldError};
+    ///
+    /// #[derive(FieldAccess)]
+    /// struct Foo {
+    ///     a: u8,
+    ///     #[field_access(readonly)]
+    ///     b: u8,
+    /// }
+    ///
+    /// let mut foo = Foo { a: 1, b: 2 };
+    ///
+    /// assert!(foo.try_field_mut("a").is_ok());
+    /// assert_eq!(foo.try_field_mut("b").unwrap_err(), FieldError::ReadOnly { name: "b" });
+    /// assert!(matches!(foo.try_field_mut("c"), Err(FieldError::NoSuchField { name: "c", .. })));
+    /// ```
+    #[inline]
+    fn try_field_mut<'n>(&mut self, field: &'n str) -> Result<FieldMut<'_>, FieldError<'n>> {
+        if self.field_as_any_mut(field).is_none() {
+            return Err(if self.field_as_any(field).is_some() {
+                FieldError::ReadOnly { name: field }
+            } else {
+                FieldError::NoSuchField {
+                    name: field,
+                    suggestion: self.suggest_field(field),
+                }
+            });
+        }
+
+        self.field_ref_mut(field).ok_or(FieldError::NoSuchField {
+            name: field,
+            suggestion: None,
+        })
     }
 
     /// Returns an iterator over all struct fields.
@@ -188,29 +910,339 @@ pub trait FieldAccess: AnyFieldAccess {
     ///                      ("c", Some(3))])
     /// ```
     #[inline]
-    fn fields(&self) -> Fields<'_>
+    fn fields(&self) -> Fields<'_>
+    where
+        Self: Sized,
+    {
+        Fields::new(self, None)
+    }
+
+    /// Returns an iterator over all readable struct fields in `group`.
+    ///
+    /// Fields are added to groups via `#[field_access(group = "...")]`. Fields of flattened
+    /// structs are included. As for [`.fields()`](Self::fields), the order of the items is
+    /// undefined.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use field_access::FieldAccess;
+    ///
+    /// #[derive(FieldAccess)]
+    /// struct Settings {
+    ///     #[field_access(group = "network")]
+    ///     host: &'static str,
+    ///     #[field_access(group = "network", group = "advanced")]
+    ///     port: u16,
+    ///     theme: &'static str,
+    /// }
+    ///
+    /// let settings = Settings { host: "localhost", port: 8080, theme: "dark" };
+    /// let names: Vec<_> = settings.fields_in_group("network").map(|(name, _)| name).collect();
+    ///
+    /// assert_eq!(names, ["host", "port"]);
+    /// assert_eq!(settings.fields_in_group("advanced").count(), 1);
+    /// assert_eq!(settings.fields_in_group("unknown").count(), 0);
+    /// ```
+    #[inline]
+    fn fields_in_group<'a>(&'a self, group: &'a str) -> Fields<'a>
+    where
+        Self: Sized,
+    {
+        Fields::new(self, Some(group))
+    }
+
+    /// Immutable access to a nested field via a dotted path like `"server.tls.cert_path"`.
+    ///
+    /// Each segment except the last one must name a field which implements [`AnyFieldAccess`]
+    /// (see [`AnyFieldAccess::field_as_field_access`]). Elements of sequences can be accessed by
+    /// index, e.g. `"servers[2].port"` (see [`AnyFieldAccess::field_as_sequence`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PathError`] describing the path segment which could not be resolved.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use field_access::{FieldAccess, PathErrorKind};
+    ///
+    /// #[derive(FieldAccess)]
+    /// struct Tls {
+    ///     cert_path: &'static str,
+    /// }
+    ///
+    /// #[derive(FieldAccess)]
+    /// struct Server {
+    ///     tls: Tls,
+    ///     port: u16,
+    /// }
+    ///
+    /// let server = Server { tls: Tls { cert_path: "cert.pem" }, port: 443 };
+    ///
+    /// assert_eq!(server.field_path("tls.cert_path").unwrap().as_str(), Some("cert.pem"));
+    /// assert_eq!(server.field_path("port").unwrap().as_u16(), Some(443));
+    ///
+    /// let err = server.field_path("port.number").unwrap_err();
+    ///
+    /// assert_eq!(err.segment(), "port");
+    /// assert_eq!(err.kind(), PathErrorKind::NotFieldAccess);
+    /// ```
+    #[inline]
+    fn field_path<'p>(&self, path: &'p str) -> Result<Field<'_>, PathError<'p>>
+    where
+        Self: Sized,
+    {
+        path::field(self, path)
+    }
+
+    /// Mutable access to a nested field via a dotted path like `"server.tls.cert_path"`.
+    ///
+    /// Each segment except the last one must name a field which implements [`AnyFieldAccess`]
+    /// (see [`AnyFieldAccess::field_as_field_access_mut`]). Elements of sequences can be accessed
+    /// by index, e.g. `"servers[2].port"` (see [`AnyFieldAccess::field_as_sequence_mut`]). The
+    /// elements of `&[T]` fields cannot be accessed mutably.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PathError`] describing the path segment which could not be resolved.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use field_access::FieldAccess;
+    ///
+    /// #[derive(FieldAccess)]
+    /// struct Tls {
+    ///     cert_path: &'static str,
+    /// }
+    ///
+    /// #[derive(FieldAccess)]
+    /// struct Server {
+    ///     tls: Tls,
+    /// }
+    ///
+    /// let mut server = Server { tls: Tls { cert_path: "cert.pem" } };
+    /// let mut field = server.field_path_mut("tls.cert_path").unwrap();
+    ///
+    /// assert!(field.set("other.pem"));
+    /// assert_eq!(server.tls.cert_path, "other.pem");
+    /// ```
+    #[inline]
+    fn field_path_mut<'p>(&mut self, path: &'p str) -> Result<FieldMut<'_>, PathError<'p>>
+    where
+        Self: Sized,
+    {
+        path::field_mut(self, path)
+    }
+
+    /// Immutable access to a nested field via a JSON Pointer ([RFC 6901]) like `"/servers/0/port"`.
+    ///
+    /// Reference tokens are resolved through fields which implement [`AnyFieldAccess`] and
+    /// through the elements of sequences (see [`AnyFieldAccess::field_as_sequence`]). The escape
+    /// sequences `~0` and `~1` are supported. The empty pointer `""` references `self`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PathError`] describing the reference token which could not be resolved.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use field_access::{FieldAccess, PathErrorKind};
+    ///
+    /// #[derive(FieldAccess)]
+    /// struct Server {
+    ///     port: u16,
+    /// }
+    ///
+    /// #[derive(FieldAccess)]
+    /// struct Config {
+    ///     servers: Vec<Server>,
+    ///     #[field_access(rename = "a/b")]
+    ///     ab: bool,
+    /// }
+    ///
+    /// let config = Config { servers: vec![Server { port: 443 }], ab: true };
+    ///
+    /// assert_eq!(config.pointer("/servers/0/port").unwrap().as_u16(), Some(443));
+    /// assert_eq!(config.pointer("/a~1b").unwrap().as_bool(), Some(true));
+    ///
+    /// let err = config.pointer("/servers/1/port").unwrap_err();
+    ///
+    /// assert_eq!(err.segment(), "1");
+    /// assert_eq!(err.kind(), PathErrorKind::IndexOutOfBounds);
+    /// ```
+    ///
+    /// [RFC 6901]: https://www.rfc-editor.org/rfc/rfc6901
+    #[inline]
+    fn pointer<'p>(&self, pointer: &'p str) -> Result<Field<'_>, PathError<'p>>
+    where
+        Self: Sized,
+    {
+        pointer::field(self, pointer)
+    }
+
+    /// Mutable access to a nested field via a JSON Pointer ([RFC 6901]) like `"/servers/0/port"`.
+    ///
+    /// See [`.pointer()`](Self::pointer) for more. The elements of `&[T]` fields cannot be
+    /// accessed mutably.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PathError`] describing the reference token which could not be resolved.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use field_access::FieldAccess;
+    ///
+    /// #[derive(FieldAccess)]
+    /// struct Config {
+    ///     ports: Vec<u16>,
+    /// }
+    ///
+    /// let mut config = Config { ports: vec![80, 443] };
+    ///
+    /// assert!(config.pointer_mut("/ports/1").unwrap().set(8443u16));
+    /// assert_eq!(config.ports, &[80, 8443]);
+    /// ```
+    ///
+    /// [RFC 6901]: https://www.rfc-editor.org/rfc/rfc6901
+    #[inline]
+    fn pointer_mut<'p>(&mut self, pointer: &'p str) -> Result<FieldMut<'_>, PathError<'p>>
+    where
+        Self: Sized,
+    {
+        pointer::field_mut(self, pointer)
+    }
+
+    /// Returns an iterator over the names of all fields including the fields of
+    /// [flattened](AnyFieldAccess::flattened) structs.
+    ///
+    /// The names of the struct's own fields are yielded first, followed by the names of the fields
+    /// of the flattened structs. Each name is yielded only once since fields which are
+    /// [shadowed](AnyFieldAccess::flattened) by a preceding field of the same name are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use field_access::{AnyFieldAccess, FieldAccess};
+    ///
+    /// #[derive(FieldAccess, Default)]
+    /// struct Tls {
+    ///     cert_path: &'static str,
+    /// }
+    ///
+    /// #[derive(FieldAccess, Default)]
+    /// struct Server {
+    ///     #[field_access(flatten)]
+    ///     tls: Tls,
+    ///     port: u16,
+    /// }
+    ///
+    /// let server = Server::default();
+    /// let names: Vec<_> = server.all_field_names().collect();
+    ///
+    /// assert_eq!(server.field_names(), &["port"]);
+    /// assert_eq!(names, &["port", "cert_path"]);
+    /// ```
+    #[inline]
+    fn all_field_names(&self) -> FieldNames<'_>
     where
         Self: Sized,
     {
-        Fields::new(self)
+        FieldNames::new(self)
     }
 }
 
-impl<T> FieldAccess for T where T: AnyFieldAccess {}
+impl<T> FieldAccess for T where T: AnyFieldAccess + ?Sized {}
+
+// Returns `true` if `name` is the name or an alias of a field of `access` or one of its flattened
+// structs, regardless of whether the field is readable.
+fn is_field_name<T>(access: &T, name: &str) -> bool
+where
+    T: AnyFieldAccess + ?Sized,
+{
+    is_own_field_name(access, name)
+        || (0..)
+            .map_while(|index| access.flattened(index))
+            .any(|flattened| is_field_name(flattened, name))
+}
+
+// Returns `true` if `name` is the name or an alias of a field of `access` itself.
+//
+// This uses `field_index` since the derive macro implements it with a dispatch on the name instead
+// of comparing it against every field name and alias.
+fn is_own_field_name<T>(access: &T, name: &str) -> bool
+where
+    T: AnyFieldAccess + ?Sized,
+{
+    access.field_index(name).is_some()
+}
 
 /// An immutable struct field reference.
 ///
 /// A `FieldRef` is a proxy for immutable operations on a struct's field.
 ///
 /// Values of this type are created by [`FieldAccess::field`].
-#[derive(Debug, Clone)]
+///
+/// `Field` does not implement `Clone` since a [`FieldMut`] dereferences to a `Field` with the
+/// same lifetime. A clone would allow to keep a shared reference to the value while it is
+/// modified through the `FieldMut`.
+#[repr(C)]
 pub struct Field<'a> {
-    value: &'a dyn Any,
+    value: Value<'a>,
+}
+
+// The value of a field. Fields whose type implements `AnyFieldAccess` keep that information so
+// that they can be accessed recursively. The name of the field's type is only known for values
+// which are not provided as `&dyn Any`.
+//
+// `Value` and `ValueMut` must have the same memory layout, see `FieldMut::as_ref`.
+#[derive(Clone, Copy)]
+#[repr(u8)]
+enum Value<'a> {
+    Any(&'a dyn Any),
+    Typed(&'a dyn __private::AsDynAny),
+    Access(&'a dyn AnyFieldAccess),
+}
+
+#[repr(u8)]
+enum ValueMut<'a> {
+    Any(&'a mut dyn Any),
+    Typed(&'a mut dyn __private::AsDynAny),
+    Access(&'a mut dyn AnyFieldAccess),
 }
 
 impl<'a> Field<'a> {
-    fn new(value: &'a dyn Any) -> Self {
-        Field { value }
+    // Looks up a field of `access` by name.
+    fn lookup<T>(access: &'a T, field: &str) -> Option<Self>
+    where
+        T: AnyFieldAccess + ?Sized,
+    {
+        match access.field_as_field_access(field) {
+            Some(access) => Some(Field::from_access(access)),
+            None => access.field_as_any(field).map(Field::from_any),
+        }
+    }
+
+    fn from_any(value: &'a dyn Any) -> Self {
+        Field {
+            value: Value::Any(value),
+        }
+    }
+
+    fn from_value<T: Any>(value: &'a T) -> Self {
+        Field {
+            value: Value::Typed(value),
+        }
+    }
+
+    fn from_access(access: &'a dyn AnyFieldAccess) -> Self {
+        Field {
+            value: Value::Access(access),
+        }
     }
 
     /// Returns `true` if the field is of type `T`.
@@ -233,7 +1265,7 @@ impl<'a> Field<'a> {
     /// ```
     #[inline]
     pub fn is<T: Any>(&self) -> bool {
-        self.value.is::<T>()
+        self.as_any().is::<T>()
     }
 
     /// Gets the `TypeId` of the field's value.
@@ -256,7 +1288,38 @@ impl<'a> Field<'a> {
     /// ```
     #[inline]
     pub fn type_id(&self) -> TypeId {
-        self.value.type_id()
+        self.as_any().type_id()
+    }
+
+    /// Gets the name of the type of the field's value as returned by [`core::any::type_name`].
+    ///
+    /// Returns `None` if the type name is unknown. This is the case for fields of manual
+    /// `AnyFieldAccess` implementations, which are provided as `&dyn Any`, unless the field's type
+    /// implements `AnyFieldAccess` itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use field_access::FieldAccess;
+    ///
+    /// #[derive(FieldAccess)]
+    /// struct Foo {
+    ///     a: u8,
+    ///     b: Vec<&'static str>,
+    /// }
+    ///
+    /// let foo = Foo { a: 1, b: vec!["b"] };
+    ///
+    /// assert_eq!(foo.field("a").unwrap().type_name(), Some("u8"));
+    /// assert_eq!(foo.field("b").unwrap().type_name(), Some("alloc::vec::Vec<&str>"));
+    /// ```
+    #[inline]
+    pub fn type_name(&self) -> Option<&'static str> {
+        match self.value {
+            Value::Any(_) => None,
+            Value::Typed(value) => Some(value.dyn_type_name()),
+            Value::Access(access) => Some(access.dyn_type_name()),
+        }
     }
 
     /// Obtains an immutable reference to the value of type `T`.
@@ -281,7 +1344,35 @@ impl<'a> Field<'a> {
     /// ```
     #[inline]
     pub fn get<T: Any>(&self) -> Option<&T> {
-        self.value.downcast_ref::<T>()
+        self.as_any().downcast_ref::<T>()
+    }
+
+    /// Obtains an immutable reference to the value of type `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FieldError::TypeMismatch`] if the field's value is not of type `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use field_access::FieldAccess;
+    ///
+    /// #[derive(FieldAccess)]
+    /// struct Foo {
+    ///     a: u8
+    /// }
+    ///
+    /// let foo = Foo { a: 42 };
+    /// let field = foo.field("a").unwrap();
+    ///
+    /// assert_eq!(field.try_get::<u8>(), Ok(&42u8));
+    /// assert!(field.try_get::<&str>().is_err());
+    /// ```
+    #[inline]
+    pub fn try_get<T: Any>(&self) -> Result<&T, FieldError<'static>> {
+        self.get()
+            .ok_or_else(|| FieldError::type_mismatch::<T>(self))
     }
 
     /// Obtains an immutable reference to the value as `&dyn Any`.
@@ -304,7 +1395,54 @@ impl<'a> Field<'a> {
     /// ```
     #[inline]
     pub fn as_any(&self) -> &dyn Any {
-        self.value
+        match self.value {
+            Value::Any(value) => value,
+            Value::Typed(value) => value.as_dyn_any(),
+            Value::Access(access) => access.as_dyn_any(),
+        }
+    }
+
+    /// Obtains an immutable reference to the value as `&dyn AnyFieldAccess`.
+    ///
+    /// Returns `Some(_)` if the field's type implements [`AnyFieldAccess`], `None` otherwise. See
+    /// [`AnyFieldAccess::field_as_field_access`] for details on how this is detected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use field_access::FieldAccess;
+    ///
+    /// #[derive(FieldAccess)]
+    /// struct Tls {
+    ///     cert_path: &'static str,
+    /// }
+    ///
+    /// #[derive(FieldAccess)]
+    /// struct Server {
+    ///     tls: Tls,
+    ///     port: u16,
+    /// }
+    ///
+    /// let server = Server { tls: Tls { cert_path: "cert.pem" }, port: 443 };
+    /// let field = server.field("tls").unwrap();
+    /// let tls = field.as_field_access().unwrap();
+    ///
+    /// assert_eq!(tls.field("cert_path").unwrap().as_str(), Some("cert.pem"));
+    /// assert!(server.field("port").unwrap().as_field_access().is_none());
+    /// ```
+    #[inline]
+    pub fn as_field_access(&self) -> Option<&dyn AnyFieldAccess> {
+        match self.value {
+            Value::Any(_) | Value::Typed(_) => None,
+            Value::Access(access) => Some(access),
+        }
+    }
+
+    fn into_field_access(self) -> Option<&'a dyn AnyFieldAccess> {
+        match self.value {
+            Value::Any(_) | Value::Typed(_) => None,
+            Value::Access(access) => Some(access),
+        }
     }
 
     /// Returns `true` if the field value is of type `&[T]`.
@@ -352,7 +1490,7 @@ impl<'a> Field<'a> {
     #[cfg(feature = "alloc")]
     pub fn as_slice<T: Any>(&self) -> Option<&[T]> {
         get_downcast_ref!(
-            self.value,
+            self.as_any(),
             &[T] => |&v| Some(v),
             Vec<T> => |v| Some(v.as_slice())
         )
@@ -476,7 +1614,7 @@ impl<'a> Field<'a> {
     #[cfg(feature = "alloc")]
     pub fn as_str(&self) -> Option<&str> {
         get_downcast_ref!(
-            self.value,
+            self.as_any(),
             &str => |&v| Some(v),
             String => |v| Some(v.as_str())
         )
@@ -570,14 +1708,71 @@ impl<'a> Field<'a> {
 /// A `FieldMut` is a proxy for mutable operations on a struct's field.
 ///
 /// Values of this type are created by [`FieldAccess::field_mut`].
-#[derive(Debug)]
+///
+/// A `FieldMut` dereferences to a [`Field`] for immutable operations. References obtained that
+/// way borrow the `FieldMut`, so the field cannot be modified while they are alive:
+///
+/// ```compile_fail
+/// use field_access::FieldAccess;
+///
+/// #[derive(FieldAccess)]
+/// struct Inner {
+///     name: &'static str,
+/// }
+///
+/// #[derive(FieldAccess)]
+/// struct Outer {
+///     inner: Inner,
+/// }
+///
+/// let mut outer = Outer { inner: Inner { name: "a" } };
+/// let mut field = outer.field_mut("inner").unwrap();
+///
+/// let inner = field.as_field_access().unwrap();
+/// field.set(Inner { name: "b" });
+///
+/// assert_eq!(inner.field("name").unwrap().as_str(), Some("a"));
+/// ```
+#[repr(C)]
 pub struct FieldMut<'a> {
-    value: &'a mut dyn Any,
+    value: ValueMut<'a>,
 }
 
 impl<'a> FieldMut<'a> {
-    fn new(value: &'a mut dyn Any) -> Self {
-        FieldMut { value }
+    // Looks up a field of `access` by name.
+    fn lookup<T>(access: &'a mut T, field: &str) -> Option<Self>
+    where
+        T: AnyFieldAccess + ?Sized,
+    {
+        match lookup_mut!(|access| access.field_as_field_access_mut(field)) {
+            Ok(access) => Some(FieldMut::from_access(access)),
+            Err(access) => access.field_as_any_mut(field).map(FieldMut::from_any),
+        }
+    }
+
+    fn from_any(value: &'a mut dyn Any) -> Self {
+        FieldMut {
+            value: ValueMut::Any(value),
+        }
+    }
+
+    fn from_value<T: Any>(value: &'a mut T) -> Self {
+        FieldMut {
+            value: ValueMut::Typed(value),
+        }
+    }
+
+    fn from_access(access: &'a mut dyn AnyFieldAccess) -> Self {
+        FieldMut {
+            value: ValueMut::Access(access),
+        }
+    }
+
+    fn into_field_access_mut(self) -> Option<&'a mut dyn AnyFieldAccess> {
+        match self.value {
+            ValueMut::Any(_) | ValueMut::Typed(_) => None,
+            ValueMut::Access(access) => Some(access),
+        }
     }
 
     /// Obtains a mutable reference to the value of type `T`.
@@ -606,7 +1801,18 @@ impl<'a> FieldMut<'a> {
     /// ```
     #[inline]
     pub fn get_mut<T: Any>(&mut self) -> Option<&mut T> {
-        self.value.downcast_mut::<T>()
+        self.as_any_mut().downcast_mut::<T>()
+    }
+
+    /// Obtains a mutable reference to the value of type `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FieldError::TypeMismatch`] if the field's value is not of type `T`.
+    #[inline]
+    pub fn try_get_mut<T: Any>(&mut self) -> Result<&mut T, FieldError<'static>> {
+        let error = FieldError::type_mismatch::<T>(self);
+        self.get_mut().ok_or(error)
     }
 
     /// Obtains a mutable reference to the value as `&mut dyn Any`.
@@ -634,7 +1840,46 @@ impl<'a> FieldMut<'a> {
     /// ```
     #[inline]
     pub fn as_any_mut(&mut self) -> &mut dyn Any {
-        self.value
+        match &mut self.value {
+            ValueMut::Any(value) => *value,
+            ValueMut::Typed(value) => value.as_dyn_any_mut(),
+            ValueMut::Access(access) => access.as_dyn_any_mut(),
+        }
+    }
+
+    /// Obtains a mutable reference to the value as `&mut dyn AnyFieldAccess`.
+    ///
+    /// Returns `Some(_)` if the field's type implements [`AnyFieldAccess`], `None` otherwise. See
+    /// [`AnyFieldAccess::field_as_field_access`] for details on how this is detected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use field_access::FieldAccess;
+    ///
+    /// #[derive(FieldAccess)]
+    /// struct Tls {
+    ///     cert_path: &'static str,
+    /// }
+    ///
+    /// #[derive(FieldAccess)]
+    /// struct Server {
+    ///     tls: Tls,
+    /// }
+    ///
+    /// let mut server = Server { tls: Tls { cert_path: "cert.pem" } };
+    /// let mut field = server.field_mut("tls").unwrap();
+    /// let tls = field.as_field_access_mut().unwrap();
+    ///
+    /// assert!(tls.field_mut("cert_path").unwrap().set("other.pem"));
+    /// assert_eq!(server.tls.cert_path, "other.pem");
+    /// ```
+    #[inline]
+    pub fn as_field_access_mut(&mut self) -> Option<&mut dyn AnyFieldAccess> {
+        match &mut self.value {
+            ValueMut::Any(_) | ValueMut::Typed(_) => None,
+            ValueMut::Access(access) => Some(*access),
+        }
     }
 
     /// Sets the value of the field.
@@ -663,6 +1908,34 @@ impl<'a> FieldMut<'a> {
         self.replace(value).is_some()
     }
 
+    /// Sets the value of the field.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FieldError::TypeMismatch`] if the field's value is not of type `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use field_access::FieldAccess;
+    ///
+    /// #[derive(FieldAccess)]
+    /// struct Foo {
+    ///     a: u8
+    /// }
+    ///
+    /// let mut foo = Foo { a: 1 };
+    /// let mut field = foo.field_mut("a").unwrap();
+    ///
+    /// assert!(field.try_set(42u8).is_ok());
+    /// assert!(field.try_set(42u16).is_err());
+    /// assert_eq!(foo.a, 42);
+    /// ```
+    #[inline]
+    pub fn try_set<T: Any>(&mut self, value: T) -> Result<(), FieldError<'static>> {
+        self.try_replace(value).map(|_| ())
+    }
+
     /// Replaces the value of the field, returning the previous value.
     ///
     /// Returns `Some(old_value)` if it was possible to replace the field's value with a value of
@@ -689,6 +1962,16 @@ impl<'a> FieldMut<'a> {
         self.get_mut().map(|dest| mem::replace(dest, value))
     }
 
+    /// Replaces the value of the field, returning the previous value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FieldError::TypeMismatch`] if the field's value is not of type `T`.
+    #[inline]
+    pub fn try_replace<T: Any>(&mut self, value: T) -> Result<T, FieldError<'static>> {
+        self.try_get_mut().map(|dest| mem::replace(dest, value))
+    }
+
     /// Swaps the value of the field and another mutable location.
     ///
     /// Returns `true` if it was possible to replace the field's value with a value of type `T`,
@@ -785,14 +2068,37 @@ impl<'a> FieldMut<'a> {
     as_type_mut_method!(f32, f64);
 }
 
+// The cast in `AsRef` below relies on `FieldMut` and `Field` having the same layout.
+const _: () = assert!(
+    mem::size_of::<FieldMut<'static>>() == mem::size_of::<Field<'static>>()
+        && mem::align_of::<FieldMut<'static>>() == mem::align_of::<Field<'static>>()
+);
+
 impl<'a> AsRef<Field<'a>> for FieldMut<'a> {
     fn as_ref(&self) -> &Field<'a> {
-        // SAFETY: `FieldMut` and `Field` share the same memory layout and we're holding an
-        // immutable reference.
+        // SAFETY: `FieldMut` and `Field` are `#[repr(C)]` structs with identical fields except
+        // for `ValueMut` and `Value`, which share the same memory layout due to `#[repr(u8)]`, and
+        // we're holding an immutable reference.
         unsafe { &*(self as *const FieldMut).cast::<Field>() }
     }
 }
 
+impl fmt::Debug for Field<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Field")
+            .field("value", &self.as_any())
+            .finish()
+    }
+}
+
+impl fmt::Debug for FieldMut<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FieldMut")
+            .field("value", &self.as_any())
+            .finish()
+    }
+}
+
 impl<'a> ops::Deref for FieldMut<'a> {
     type Target = Field<'a>;
 
@@ -801,27 +2107,229 @@ impl<'a> ops::Deref for FieldMut<'a> {
     }
 }
 
+/// An iterator over the names of all fields of a struct including the fields of flattened structs.
+///
+/// Values of this type are created by [`FieldAccess::all_field_names`].
+#[derive(Clone)]
+pub struct FieldNames<'a> {
+    access: &'a dyn AnyFieldAccess,
+    front: usize,
+    back: usize,
+}
+
+impl<'a> FieldNames<'a> {
+    fn new(access: &'a dyn AnyFieldAccess) -> Self {
+        FieldNames {
+            access,
+            front: 0,
+            back: count_field_names(access),
+        }
+    }
+
+    // Returns the name of the `n`th field unless it is shadowed.
+    fn nth_name(&self, n: usize) -> Option<&'static str> {
+        let name = nth_field_name(self.access, n)?;
+        (!is_shadowed(self.access, name, n)).then_some(name)
+    }
+}
+
+fn count_field_names(access: &dyn AnyFieldAccess) -> usize {
+    let flattened = (0..)
+        .map_while(|index| access.flattened(index))
+        .map(count_field_names)
+        .sum::<usize>();
+
+    access.field_names().len() + flattened
+}
+
+fn nth_field_name(access: &dyn AnyFieldAccess, n: usize) -> Option<&'static str> {
+    let (access, index) = nth_field(access, n)?;
+    access.field_names().get(index).copied()
+}
+
+// Returns the struct which contains the `n`th field in the order of `FieldNames` together with the
+// index of the field in its `field_names`.
+fn nth_field(access: &dyn AnyFieldAccess, mut n: usize) -> Option<(&dyn AnyFieldAccess, usize)> {
+    let len = access.field_names().len();
+
+    if n < len {
+        return Some((access, n));
+    }
+
+    n -= len;
+
+    for flattened in (0..).map_while(|index| access.flattened(index)) {
+        let count = count_field_names(flattened);
+
+        if n < count {
+            return nth_field(flattened, n);
+        }
+
+        n -= count;
+    }
+
+    None
+}
+
+// Returns `true` if the `n`th field in the order of `FieldNames`, whose name is `name`, is shadowed
+// by a preceding field of the same name. Lookups of `name` resolve to the preceding field then.
+fn is_shadowed(access: &dyn AnyFieldAccess, name: &str, mut n: usize) -> bool {
+    let len = access.field_names().len();
+
+    if n < len {
+        return false;
+    }
+
+    if is_own_field_name(access, name) {
+        return true;
+    }
+
+    n -= len;
+
+    for flattened in (0..).map_while(|index| access.flattened(index)) {
+        let count = count_field_names(flattened);
+
+        if n < count {
+            return is_shadowed(flattened, name, n);
+        }
+
+        if is_field_name(flattened, name) {
+            return true;
+        }
+
+        n -= count;
+    }
+
+    false
+}
+
+impl fmt::Debug for FieldNames<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl Iterator for FieldNames<'_> {
+    type Item = &'static str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.front < self.back {
+            let n = self.front;
+            self.front += 1;
+
+            if let Some(name) = self.nth_name(n) {
+                return Some(name);
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.front..self.back)
+            .filter(|n| self.nth_name(*n).is_some())
+            .count();
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for FieldNames<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while self.front < self.back {
+            self.back -= 1;
+
+            if let Some(name) = self.nth_name(self.back) {
+                return Some(name);
+            }
+        }
+
+        None
+    }
+}
+
+impl ExactSizeIterator for FieldNames<'_> {}
+impl FusedIterator for FieldNames<'_> {}
+
 /// An immutable iterator over all fields of a struct.
 ///
-/// Values of this type are created by [`FieldAccess::fields`].
+/// This includes the fields of flattened structs. Fields which are not readable (e.g. because
+/// they are marked as `#[field_access(writeonly)]`) are skipped.
+///
+/// Values of this type are created by [`FieldAccess::fields`] and
+/// [`FieldAccess::fields_in_group`].
 #[derive(Clone)]
 pub struct Fields<'a> {
-    access: &'a dyn FieldAccess,
-    field_names: slice::Iter<'a, &'static str>,
+    access: &'a dyn AnyFieldAccess,
+    group: Option<&'a str>,
+    front: usize,
+    back: usize,
+    // The number of remaining fields. It is counted on demand and then kept up to date.
+    len: Cell<Option<usize>>,
 }
 
 impl<'a> Fields<'a> {
-    fn new(access: &'a dyn FieldAccess) -> Self {
+    fn new<T: AnyFieldAccess>(access: &'a T, group: Option<&'a str>) -> Self {
         Fields {
             access,
-            field_names: access.field_names().iter(),
+            group,
+            front: 0,
+            back: count_field_names(access),
+            len: Cell::new(None),
+        }
+    }
+
+    // Returns the struct containing the `n`th field, the index of the field in it and its name
+    // unless the field is shadowed or not in the group.
+    fn field_position(&self, n: usize) -> Option<(&'a dyn AnyFieldAccess, usize, &'static str)> {
+        let (access, index) = nth_field(self.access, n)?;
+        let name = access.field_names().get(index)?;
+        let in_group = self.group.map_or(true, |group| {
+            access
+                .field_info_at(index)
+                .map_or(false, |info| info.in_group(group))
+        });
+
+        (in_group && !is_shadowed(self.access, name, n)).then_some((access, index, name))
+    }
+
+    // Returns the `n`th field if it is yielded by the iterator. Fields which are not readable are
+    // skipped by `field_ref_at`.
+    fn nth_field(&mut self, n: usize) -> Option<(&'static str, Field<'a>)> {
+        let (access, index, name) = self.field_position(n)?;
+        let field = access.field_ref_at(index)?;
+
+        if let Some(len) = self.len.get_mut() {
+            *len -= 1;
+        }
+
+        Some((name, field))
+    }
+
+    // Returns the number of remaining fields.
+    fn remaining(&self) -> usize {
+        if let Some(len) = self.len.get() {
+            return len;
         }
+
+        let len = (self.front..self.back)
+            .filter_map(|n| self.field_position(n))
+            .filter(|(access, index, _)| match access.field_info_at(*index) {
+                Some(info) => info.is_readable(),
+                None => access.field_at(*index).is_some(),
+            })
+            .count();
+
+        self.len.set(Some(len));
+        len
     }
 }
 
+#[allow(clippy::elidable_lifetime_names)]
 impl<'a> fmt::Debug for Fields<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_list().entries(self.access.field_names()).finish()
+        f.debug_list()
+            .entries(self.clone().map(|(name, _)| name))
+            .finish()
     }
 }
 
@@ -829,23 +2337,40 @@ impl<'a> Iterator for Fields<'a> {
     type Item = (&'static str, Field<'a>);
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.field_names
-            .next()
-            .and_then(|&name| self.access.field(name).map(|field| (name, field)))
+        while self.front < self.back {
+            let n = self.front;
+            self.front += 1;
+
+            if let Some(item) = self.nth_field(n) {
+                return Some(item);
+            }
+        }
+
+        None
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.field_names.size_hint()
+        let len = self.remaining();
+        (len, Some(len))
     }
 }
 
+#[allow(clippy::elidable_lifetime_names)]
 impl<'a> DoubleEndedIterator for Fields<'a> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        self.field_names
-            .next_back()
-            .and_then(|&name| self.access.field(name).map(|field| (name, field)))
+        while self.front < self.back {
+            self.back -= 1;
+
+            if let Some(item) = self.nth_field(self.back) {
+                return Some(item);
+            }
+        }
+
+        None
     }
 }
 
+#[allow(clippy::elidable_lifetime_names)]
 impl<'a> ExactSizeIterator for Fields<'a> {}
+#[allow(clippy::elidable_lifetime_names)]
 impl<'a> FusedIterator for Fields<'a> {}
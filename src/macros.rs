@@ -39,7 +39,7 @@ macro_rules! as_type_method {
                 #[doc = "assert!(field.as_" $ty:lower "().is_some());"]
                 /// ```
                 pub fn [<as_ $ty>](&self) -> Option<$ty> {
-                    get_downcast_ref!(self.value, $ty => |&v| Some(v), $($($mapping)*)*)
+                    get_downcast_ref!(self.as_any(), $ty => |&v| Some(v), $($($mapping)*)*)
                 }
             }
         )*
@@ -121,3 +121,27 @@ macro_rules! is_type_method {
         )*
     };
 }
+
+// Evaluates the mutable lookup `$lookup` with `$access` reborrowed and yields `Ok(_)` with the
+// found value or `Err(_)` with `$access` if the lookup returned `None`.
+//
+// Matching on the lookup directly and using `$access` again in the `None` arm is rejected by the
+// borrow checker if the found value is returned from the function, although nothing is borrowed
+// in that case (a known limitation of NLL). Repeating the lookup after checking its result is no
+// alternative since `AnyFieldAccess` implementations are not required to return the same result
+// both times.
+macro_rules! lookup_mut {
+    (|$access:ident| $lookup:expr) => {{
+        let ptr: *mut _ = $access;
+        // SAFETY: `ptr` is derived from a unique reference. The reborrow given to `$lookup` is
+        // only kept alive by the value it returns, so it is unused if the lookup returned `None`
+        // and `$access` can be handed out again.
+        match {
+            let $access = unsafe { &mut *ptr };
+            $lookup
+        } {
+            Some(value) => Ok(value),
+            None => Err(unsafe { &mut *ptr }),
+        }
+    }};
+}
@@ -0,0 +1,297 @@
+//! Access to nested struct fields via dotted paths.
+
+use crate::{AnyFieldAccess, Field, FieldMut};
+use core::fmt;
+use core::ops::Range;
+
+/// The reason why a field path could not be resolved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PathErrorKind {
+    /// The path segment does not name an accessible field.
+    NoSuchField,
+    /// The path segment names a field which is followed by more segments, but the field does not
+    /// implement [`AnyFieldAccess`].
+    NotFieldAccess,
+    /// The path segment indexes a field which is not a sequence. See [`AnySequence`](crate::AnySequence).
+    NotSequence,
+    /// The path segment indexes a sequence, but the index is out of bounds.
+    IndexOutOfBounds,
+    /// The path segment indexes a sequence whose elements cannot be accessed mutably, e.g. a
+    /// `&[T]` field.
+    NotMutable,
+    /// The path segment is malformed, e.g. because it contains an invalid index.
+    InvalidSegment,
+}
+
+/// An error returned when a field path cannot be resolved.
+///
+/// Values of this type are returned by [`FieldAccess::field_path`](crate::FieldAccess::field_path),
+/// [`FieldAccess::pointer`](crate::FieldAccess::pointer) and their mutable counterparts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathError<'p> {
+    path: &'p str,
+    segment: Range<usize>,
+    kind: PathErrorKind,
+}
+
+impl<'p> PathError<'p> {
+    pub(crate) fn new(path: &'p str, segment: Range<usize>, kind: PathErrorKind) -> Self {
+        PathError {
+            path,
+            segment,
+            kind,
+        }
+    }
+
+    /// Returns the path which failed to resolve.
+    pub fn path(&self) -> &'p str {
+        self.path
+    }
+
+    /// Returns the path segment which failed to resolve.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use field_access::FieldAccess;
+    ///
+    /// #[derive(FieldAccess)]
+    /// struct Tls {
+    ///     cert_path: &'static str,
+    /// }
+    ///
+    /// #[derive(FieldAccess)]
+    /// struct Server {
+    ///     tls: Tls,
+    /// }
+    ///
+    /// let server = Server { tls: Tls { cert_path: "cert.pem" } };
+    /// let err = server.field_path("tls.key_path").unwrap_err();
+    ///
+    /// assert_eq!(err.segment(), "key_path");
+    /// assert_eq!(err.segment_range(), 4..12);
+    /// ```
+    pub fn segment(&self) -> &'p str {
+        &self.path[self.segment.clone()]
+    }
+
+    /// Returns the byte range of the failed segment within the path.
+    pub fn segment_range(&self) -> Range<usize> {
+        self.segment.clone()
+    }
+
+    /// Returns the reason why the path could not be resolved.
+    pub fn kind(&self) -> PathErrorKind {
+        self.kind
+    }
+}
+
+impl fmt::Display for PathError<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let segment = self.segment();
+        let path = self.path;
+
+        match self.kind {
+            PathErrorKind::NoSuchField => {
+                write!(f, "no such field `{segment}` in path `{path}`")
+            }
+            PathErrorKind::NotFieldAccess => {
+                write!(f, "field `{segment}` in path `{path}` has no nested fields")
+            }
+            PathErrorKind::NotSequence => {
+                write!(f, "field `{segment}` in path `{path}` is not a sequence")
+            }
+            PathErrorKind::IndexOutOfBounds => {
+                write!(f, "index out of bounds in `{segment}` in path `{path}`")
+            }
+            PathErrorKind::NotMutable => {
+                write!(
+                    f,
+                    "elements of `{segment}` in path `{path}` are not mutable"
+                )
+            }
+            PathErrorKind::InvalidSegment => {
+                write!(f, "invalid segment `{segment}` in path `{path}`")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PathError<'_> {}
+
+// A segment of a path, e.g. `port` or `servers[2]`.
+struct Segment<'p> {
+    path: &'p str,
+    range: Range<usize>,
+    name: &'p str,
+    index: Option<usize>,
+    last: bool,
+}
+
+impl<'p> Segment<'p> {
+    fn error(&self, kind: PathErrorKind) -> PathError<'p> {
+        PathError::new(self.path, self.range.clone(), kind)
+    }
+}
+
+// An iterator over the segments of a path.
+struct Segments<'p> {
+    path: &'p str,
+    remaining: &'p str,
+    offset: usize,
+}
+
+impl<'p> Segments<'p> {
+    fn new(path: &'p str) -> Self {
+        Segments {
+            path,
+            remaining: path,
+            offset: 0,
+        }
+    }
+
+    // Returns the next segment. Must not be called after the last segment was returned.
+    fn next_segment(&mut self) -> Result<Segment<'p>, PathError<'p>> {
+        let (raw, rest) = match self.remaining.split_once('.') {
+            Some((raw, rest)) => (raw, Some(rest)),
+            None => (self.remaining, None),
+        };
+        let range = self.offset..self.offset + raw.len();
+
+        self.remaining = rest.unwrap_or_default();
+        self.offset = range.end + 1;
+
+        let Some((name, index)) = parse_segment(raw) else {
+            return Err(PathError::new(
+                self.path,
+                range,
+                PathErrorKind::InvalidSegment,
+            ));
+        };
+
+        Ok(Segment {
+            path: self.path,
+            range,
+            name,
+            index,
+            last: rest.is_none(),
+        })
+    }
+}
+
+// Parses a segment into the field name and the optional index.
+fn parse_segment(raw: &str) -> Option<(&str, Option<usize>)> {
+    let Some((name, index)) = raw.split_once('[') else {
+        return (!raw.contains(']')).then_some((raw, None));
+    };
+
+    let index = index.strip_suffix(']')?;
+
+    if index.is_empty() || !index.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    Some((name, Some(index.parse().ok()?)))
+}
+
+// Resolves a single segment of a path.
+fn segment_field<'a, 'p>(
+    access: &'a dyn AnyFieldAccess,
+    segment: &Segment<'p>,
+) -> Result<Field<'a>, PathError<'p>> {
+    let Some(index) = segment.index else {
+        return access
+            .field_ref(segment.name)
+            .ok_or_else(|| segment.error(PathErrorKind::NoSuchField));
+    };
+
+    let Some(sequence) = access.field_as_sequence(segment.name) else {
+        let exists = access.field_as_any(segment.name).is_some();
+        return Err(sequence_error(segment, exists));
+    };
+
+    sequence
+        .element(index)
+        .ok_or_else(|| segment.error(PathErrorKind::IndexOutOfBounds))
+}
+
+// Resolves a single segment of a path.
+fn segment_field_mut<'a, 'p>(
+    access: &'a mut dyn AnyFieldAccess,
+    segment: &Segment<'p>,
+) -> Result<FieldMut<'a>, PathError<'p>> {
+    let Some(index) = segment.index else {
+        return access
+            .field_ref_mut(segment.name)
+            .ok_or_else(|| segment.error(PathErrorKind::NoSuchField));
+    };
+
+    let sequence = match lookup_mut!(|access| access.field_as_sequence_mut(segment.name)) {
+        Ok(sequence) => sequence,
+        Err(access) => {
+            let exists = access.field_as_any_mut(segment.name).is_some();
+            return Err(sequence_error(segment, exists));
+        }
+    };
+
+    if index >= sequence.len() {
+        return Err(segment.error(PathErrorKind::IndexOutOfBounds));
+    }
+
+    sequence
+        .element_mut(index)
+        .ok_or_else(|| segment.error(PathErrorKind::NotMutable))
+}
+
+// Returns the error for an indexed segment whose field is not a sequence.
+fn sequence_error<'p>(segment: &Segment<'p>, exists: bool) -> PathError<'p> {
+    if exists {
+        segment.error(PathErrorKind::NotSequence)
+    } else {
+        segment.error(PathErrorKind::NoSuchField)
+    }
+}
+
+pub(crate) fn field<'a, 'p>(
+    access: &'a dyn AnyFieldAccess,
+    path: &'p str,
+) -> Result<Field<'a>, PathError<'p>> {
+    let mut segments = Segments::new(path);
+    let mut current = access;
+
+    loop {
+        let segment = segments.next_segment()?;
+        let field = segment_field(current, &segment)?;
+
+        if segment.last {
+            return Ok(field);
+        }
+
+        current = field
+            .into_field_access()
+            .ok_or_else(|| segment.error(PathErrorKind::NotFieldAccess))?;
+    }
+}
+
+pub(crate) fn field_mut<'a, 'p>(
+    access: &'a mut dyn AnyFieldAccess,
+    path: &'p str,
+) -> Result<FieldMut<'a>, PathError<'p>> {
+    let mut segments = Segments::new(path);
+    let mut current = access;
+
+    loop {
+        let segment = segments.next_segment()?;
+        let field = segment_field_mut(current, &segment)?;
+
+        if segment.last {
+            return Ok(field);
+        }
+
+        current = field
+            .into_field_access_mut()
+            .ok_or_else(|| segment.error(PathErrorKind::NotFieldAccess))?;
+    }
+}
@@ -0,0 +1,242 @@
+//! Access to nested struct fields via JSON Pointers ([RFC 6901]).
+//!
+//! [RFC 6901]: https://www.rfc-editor.org/rfc/rfc6901
+
+use crate::{AnyFieldAccess, AnySequence, Field, FieldMut, FieldNames, PathError, PathErrorKind};
+use core::iter;
+use core::ops::Range;
+
+// The value a pointer resolved to so far.
+enum Node<'a> {
+    Access(&'a dyn AnyFieldAccess),
+    Sequence(&'a dyn AnySequence),
+}
+
+enum NodeMut<'a> {
+    Access(&'a mut dyn AnyFieldAccess),
+    Sequence(&'a mut dyn AnySequence),
+}
+
+// A reference token of a pointer, e.g. `port` in `/server/port`.
+struct Token<'p> {
+    pointer: &'p str,
+    range: Range<usize>,
+    last: bool,
+}
+
+impl<'p> Token<'p> {
+    fn error(&self, kind: PathErrorKind) -> PathError<'p> {
+        PathError::new(self.pointer, self.range.clone(), kind)
+    }
+
+    fn raw(&self) -> &'p str {
+        &self.pointer[self.range.clone()]
+    }
+
+    // Returns the field name referenced by the token.
+    fn name(&self, access: &dyn AnyFieldAccess) -> Result<&'p str, PathError<'p>> {
+        let raw = self.raw();
+
+        if !raw.contains('~') {
+            return Ok(raw);
+        }
+
+        if !is_valid_escape(raw) {
+            return Err(self.error(PathErrorKind::InvalidSegment));
+        }
+
+        // Escaped tokens are matched against the known field names and aliases since unescaping
+        // them would require an allocation.
+        FieldNames::new(access)
+            .flat_map(|name| iter::once(name).chain(access.field_aliases(name).iter().copied()))
+            .find(|name| unescaped_eq(raw, name))
+            .ok_or_else(|| self.error(PathErrorKind::NoSuchField))
+    }
+
+    // Returns the array index referenced by the token.
+    fn index(&self) -> Result<usize, PathError<'p>> {
+        let raw = self.raw();
+
+        // `-` references the (nonexistent) element after the last one.
+        if raw == "-" {
+            return Err(self.error(PathErrorKind::IndexOutOfBounds));
+        }
+
+        let valid = raw == "0"
+            || (!raw.is_empty()
+                && !raw.starts_with('0')
+                && raw.bytes().all(|b| b.is_ascii_digit()));
+
+        valid
+            .then(|| raw.parse().ok())
+            .flatten()
+            .ok_or_else(|| self.error(PathErrorKind::InvalidSegment))
+    }
+}
+
+// Returns the reference tokens of a pointer. `tokens` is the pointer without its leading `/`.
+fn tokens<'p>(pointer: &'p str, tokens: &'p str) -> impl Iterator<Item = Token<'p>> {
+    let mut offset = 1;
+    let mut tokens = tokens.split('/').peekable();
+
+    iter::from_fn(move || {
+        let token = tokens.next()?;
+        let range = offset..offset + token.len();
+        offset = range.end + 1;
+
+        Some(Token {
+            pointer,
+            range,
+            last: tokens.peek().is_none(),
+        })
+    })
+}
+
+// Returns `true` if every `~` in `token` is followed by `0` or `1`.
+fn is_valid_escape(token: &str) -> bool {
+    let mut bytes = token.bytes();
+
+    while let Some(b) = bytes.next() {
+        if b == b'~' && !matches!(bytes.next(), Some(b'0' | b'1')) {
+            return false;
+        }
+    }
+
+    true
+}
+
+// Returns `true` if the escaped `token` is equal to `name`.
+fn unescaped_eq(token: &str, name: &str) -> bool {
+    let mut token = token.bytes();
+    let mut name = name.bytes();
+
+    loop {
+        let expected = match token.next() {
+            Some(b'~') => match token.next() {
+                Some(b'0') => b'~',
+                Some(b'1') => b'/',
+                _ => return false,
+            },
+            Some(b) => b,
+            None => return name.next().is_none(),
+        };
+
+        if name.next() != Some(expected) {
+            return false;
+        }
+    }
+}
+
+// Returns the error for a pointer which does not start with a `/`.
+fn invalid_pointer(pointer: &str) -> PathError<'_> {
+    PathError::new(pointer, 0..pointer.len(), PathErrorKind::InvalidSegment)
+}
+
+pub(crate) fn field<'a, 'p>(
+    access: &'a dyn AnyFieldAccess,
+    pointer: &'p str,
+) -> Result<Field<'a>, PathError<'p>> {
+    let Some(rest) = pointer.strip_prefix('/') else {
+        return if pointer.is_empty() {
+            Ok(Field::from_access(access))
+        } else {
+            Err(invalid_pointer(pointer))
+        };
+    };
+
+    let mut node = Node::Access(access);
+
+    for token in tokens(pointer, rest) {
+        let field = match node {
+            Node::Access(access) => {
+                let name = token.name(access)?;
+
+                if !token.last {
+                    if let Some(sequence) = access.field_as_sequence(name) {
+                        node = Node::Sequence(sequence);
+                        continue;
+                    }
+                }
+
+                access
+                    .field_ref(name)
+                    .ok_or_else(|| token.error(PathErrorKind::NoSuchField))?
+            }
+            Node::Sequence(sequence) => sequence
+                .element(token.index()?)
+                .ok_or_else(|| token.error(PathErrorKind::IndexOutOfBounds))?,
+        };
+
+        if token.last {
+            return Ok(field);
+        }
+
+        let access = field
+            .into_field_access()
+            .ok_or_else(|| token.error(PathErrorKind::NotFieldAccess))?;
+        node = Node::Access(access);
+    }
+
+    unreachable!("a pointer has at least one reference token")
+}
+
+pub(crate) fn field_mut<'a, 'p>(
+    access: &'a mut dyn AnyFieldAccess,
+    pointer: &'p str,
+) -> Result<FieldMut<'a>, PathError<'p>> {
+    let Some(rest) = pointer.strip_prefix('/') else {
+        return if pointer.is_empty() {
+            Ok(FieldMut::from_access(access))
+        } else {
+            Err(invalid_pointer(pointer))
+        };
+    };
+
+    let mut node = NodeMut::Access(access);
+
+    for token in tokens(pointer, rest) {
+        let field = match node {
+            NodeMut::Access(access) => {
+                let name = token.name(access)?;
+
+                let access = if token.last {
+                    access
+                } else {
+                    match lookup_mut!(|access| access.field_as_sequence_mut(name)) {
+                        Ok(sequence) => {
+                            node = NodeMut::Sequence(sequence);
+                            continue;
+                        }
+                        Err(access) => access,
+                    }
+                };
+
+                access
+                    .field_ref_mut(name)
+                    .ok_or_else(|| token.error(PathErrorKind::NoSuchField))?
+            }
+            NodeMut::Sequence(sequence) => {
+                let index = token.index()?;
+
+                if index >= sequence.len() {
+                    return Err(token.error(PathErrorKind::IndexOutOfBounds));
+                }
+
+                sequence
+                    .element_mut(index)
+                    .ok_or_else(|| token.error(PathErrorKind::NotMutable))?
+            }
+        };
+
+        if token.last {
+            return Ok(field);
+        }
+
+        let access = field
+            .into_field_access_mut()
+            .ok_or_else(|| token.error(PathErrorKind::NotFieldAccess))?;
+        node = NodeMut::Access(access);
+    }
+
+    unreachable!("a pointer has at least one reference token")
+}
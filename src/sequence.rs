@@ -0,0 +1,165 @@
+//! Element access for sequence fields like `Vec<T>`, `[T; N]` and `&[T]`.
+
+use crate::{AnyFieldAccess, Field, FieldMut};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::any::Any;
+
+/// Dynamic access to the elements of a sequence.
+///
+/// Values of this type are provided by [`AnyFieldAccess::field_as_sequence`] and
+/// [`AnyFieldAccess::field_as_sequence_mut`] for fields of type `Vec<T>`, `[T; N]` and `&[T]`.
+///
+/// Elements whose type implements [`AnyFieldAccess`] can be accessed recursively via
+/// [`Field::as_field_access`].
+///
+/// # Example
+///
+/// ```
+/// use field_access::{AnyFieldAccess, FieldAccess};
+///
+/// #[derive(FieldAccess)]
+/// struct Server {
+///     port: u16,
+/// }
+///
+/// #[derive(FieldAccess)]
+/// struct Config {
+///     servers: Vec<Server>,
+///     tags: [&'static str; 2],
+/// }
+///
+/// let config = Config {
+///     servers: vec![Server { port: 80 }, Server { port: 443 }],
+///     tags: ["a", "b"],
+/// };
+///
+/// let servers = config.field_as_sequence("servers").unwrap();
+/// let server = servers.element(1).unwrap();
+///
+/// assert_eq!(servers.len(), 2);
+/// assert_eq!(server.as_field_access().unwrap().field("port").unwrap().as_u16(), Some(443));
+///
+/// let tags = config.field_as_sequence("tags").unwrap();
+///
+/// assert_eq!(tags.element(0).unwrap().as_str(), Some("a"));
+/// assert!(tags.element(2).is_none());
+/// ```
+pub trait AnySequence {
+    /// Returns the number of elements.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the sequence contains no elements.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Immutable element access.
+    ///
+    /// Returns `Some(_)` if `index` is in bounds, otherwise `None`.
+    fn element(&self, index: usize) -> Option<Field<'_>>;
+
+    /// Mutable element access.
+    ///
+    /// Returns `Some(_)` if `index` is in bounds and the sequence allows mutable access to its
+    /// elements, otherwise `None`. The elements of `&[T]` can only be accessed immutably.
+    fn element_mut(&mut self, index: usize) -> Option<FieldMut<'_>>;
+}
+
+/// Sequence types whose elements can be accessed via [`AnySequence`].
+pub trait Slice: Any {
+    type Item;
+
+    fn as_slice(&self) -> &[Self::Item];
+
+    fn as_mut_slice(&mut self) -> Option<&mut [Self::Item]>;
+}
+
+impl<T: Any, const N: usize> Slice for [T; N] {
+    type Item = T;
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+
+    fn as_mut_slice(&mut self) -> Option<&mut [T]> {
+        Some(self)
+    }
+}
+
+impl<T: Any> Slice for &'static [T] {
+    type Item = T;
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+
+    fn as_mut_slice(&mut self) -> Option<&mut [T]> {
+        None
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Any> Slice for Vec<T> {
+    type Item = T;
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+
+    fn as_mut_slice(&mut self) -> Option<&mut [T]> {
+        Some(self)
+    }
+}
+
+macro_rules! elements {
+    ($(#[$attr:meta])* $name:ident, $bound:path, $from:ident) => {
+        $(#[$attr])*
+        #[repr(transparent)]
+        pub(crate) struct $name<S>(S);
+
+        impl<S> $name<S> {
+            pub(crate) fn new(value: &S) -> &Self {
+                // SAFETY: `Self` is a transparent wrapper around `S`.
+                unsafe { &*(value as *const S).cast::<Self>() }
+            }
+
+            pub(crate) fn new_mut(value: &mut S) -> &mut Self {
+                // SAFETY: `Self` is a transparent wrapper around `S`.
+                unsafe { &mut *(value as *mut S).cast::<Self>() }
+            }
+        }
+
+        impl<S> AnySequence for $name<S>
+        where
+            S: Slice,
+            S::Item: $bound,
+        {
+            fn len(&self) -> usize {
+                self.0.as_slice().len()
+            }
+
+            fn element(&self, index: usize) -> Option<Field<'_>> {
+                self.0.as_slice().get(index).map(|value| Field::$from(value))
+            }
+
+            fn element_mut(&mut self, index: usize) -> Option<FieldMut<'_>> {
+                self.0
+                    .as_mut_slice()?
+                    .get_mut(index)
+                    .map(|value| FieldMut::$from(value))
+            }
+        }
+    };
+}
+
+elements! {
+    /// A sequence of arbitrary elements.
+    Elements, Any, from_value
+}
+
+elements! {
+    /// A sequence of elements implementing `AnyFieldAccess`.
+    AccessElements, AnyFieldAccess, from_access
+}
@@ -0,0 +1,85 @@
+//! Suggestions for misspelled field names.
+
+// The maximum length of a candidate for which the edit distance is computed. This keeps the
+// computation allocation-free.
+const MAX_LEN: usize = 64;
+
+// Returns the candidate which is closest to `name` by edit distance.
+//
+// Candidates which differ only in ASCII case are always preferred. Other candidates are only
+// considered if their edit distance to `name` is at most a third of the length of `name`.
+pub(crate) fn closest<I>(name: &str, candidates: I) -> Option<&'static str>
+where
+    I: IntoIterator<Item = &'static str>,
+{
+    let max_distance = name.chars().count().max(3) / 3;
+    let mut best: Option<(usize, &'static str)> = None;
+
+    for candidate in candidates {
+        if candidate.eq_ignore_ascii_case(name) {
+            return Some(candidate);
+        }
+
+        let Some(distance) = edit_distance(name, candidate) else {
+            continue;
+        };
+
+        let closer = match best {
+            Some((best_distance, _)) => distance < best_distance,
+            None => true,
+        };
+
+        if distance <= max_distance && closer {
+            best = Some((distance, candidate));
+        }
+    }
+
+    best.map(|(_, candidate)| candidate)
+}
+
+// Computes the edit distance between `a` and `b`, counting insertions, deletions, substitutions
+// and transpositions of adjacent characters (optimal string alignment distance).
+//
+// Returns `None` if `b` is longer than `MAX_LEN` characters.
+fn edit_distance(a: &str, b: &str) -> Option<usize> {
+    let len = b.chars().count();
+
+    if len > MAX_LEN {
+        return None;
+    }
+
+    // The distances of the previous two rows and the current row of the distance matrix.
+    let mut previous = [0; MAX_LEN + 1];
+    let mut row = [0; MAX_LEN + 1];
+    let mut current = [0; MAX_LEN + 1];
+
+    for (j, cell) in row.iter_mut().enumerate().take(len + 1) {
+        *cell = j;
+    }
+
+    let mut last_a = None;
+
+    for (i, ca) in a.chars().enumerate() {
+        current[0] = i + 1;
+        let mut last_b = None;
+
+        for (j, cb) in b.chars().enumerate() {
+            let mut distance = (row[j + 1] + 1)
+                .min(current[j] + 1)
+                .min(row[j] + usize::from(ca != cb));
+
+            if last_a == Some(cb) && last_b == Some(ca) {
+                distance = distance.min(previous[j - 1] + 1);
+            }
+
+            current[j + 1] = distance;
+            last_b = Some(cb);
+        }
+
+        previous = row;
+        row = current;
+        last_a = Some(ca);
+    }
+
+    Some(row[len])
+}
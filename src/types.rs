@@ -0,0 +1,378 @@
+//! Static information about struct fields.
+
+use core::any::TypeId;
+use core::fmt;
+
+/// The type of a struct field.
+///
+/// Values of this type are created by `#[derive(FieldAccess)]` and obtained via
+/// [`AnyFieldAccess::field_types`](crate::AnyFieldAccess::field_types) and
+/// [`AnyFieldAccess::field_type`](crate::AnyFieldAccess::field_type).
+///
+/// # Example
+///
+/// ```
+/// use field_access::{AnyFieldAccess, FieldAccess};
+///
+/// #[derive(FieldAccess)]
+/// struct Foo {
+///     a: Vec<u8>,
+/// }
+///
+/// let foo = Foo { a: vec![1] };
+/// let ty = foo.field_type("a").unwrap();
+///
+/// assert_eq!(ty.name(), "a");
+/// assert_eq!(ty.ty(), "Vec<u8>");
+/// assert_eq!(ty.type_name(), "alloc::vec::Vec<u8>");
+/// ```
+#[derive(Clone, Copy)]
+pub struct FieldType {
+    name: &'static str,
+    ty: &'static str,
+    type_name: fn() -> &'static str,
+    type_id: fn() -> TypeId,
+}
+
+impl FieldType {
+    #[doc(hidden)]
+    pub const fn new(
+        name: &'static str,
+        ty: &'static str,
+        type_name: fn() -> &'static str,
+        type_id: fn() -> TypeId,
+    ) -> Self {
+        FieldType {
+            name,
+            ty,
+            type_name,
+            type_id,
+        }
+    }
+
+    /// Returns the name of the field.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the type of the field as written in the struct definition.
+    pub fn ty(&self) -> &'static str {
+        self.ty
+    }
+
+    /// Returns the name of the field's type as returned by [`core::any::type_name`].
+    ///
+    /// In contrast to [`.ty()`](Self::ty), type parameters are substituted and paths are fully
+    /// qualified. The same caveats as for `core::any::type_name` apply.
+    pub fn type_name(&self) -> &'static str {
+        (self.type_name)()
+    }
+
+    /// Returns the `TypeId` of the field's type.
+    pub fn type_id(&self) -> TypeId {
+        (self.type_id)()
+    }
+}
+
+impl fmt::Debug for FieldType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FieldType")
+            .field("name", &self.name)
+            .field("ty", &self.ty)
+            .field("type_name", &self.type_name())
+            .field("type_id", &self.type_id())
+            .finish()
+    }
+}
+
+/// Information about a struct field which is available without an instance of the struct.
+///
+/// `#[derive(FieldAccess)]` generates an associated constant `FIELDS: &'static [FieldInfo]` for
+/// types without lifetime parameters. It lists the fields in the same order as
+/// [`AnyFieldAccess::field_names`](crate::AnyFieldAccess::field_names). For enums, it contains
+/// the fields of all variants in declaration order. Fields of flattened structs are not included.
+///
+/// # Example
+///
+/// ```
+/// use field_access::FieldAccess;
+///
+/// #[derive(FieldAccess)]
+/// struct Server {
+///     #[field_access(alias = "address")]
+///     host: String,
+///     #[field_access(readonly)]
+///     port: u16,
+/// }
+///
+/// let names: Vec<_> = Server::FIELDS.iter().map(|field| field.name()).collect();
+/// assert_eq!(names, ["host", "port"]);
+///
+/// let port = &Server::FIELDS[1];
+/// assert_eq!(port.index(), 1);
+/// assert_eq!(port.ty().ty(), "u16");
+/// assert!(port.is_readable());
+/// assert!(!port.is_writable());
+/// assert_eq!(Server::FIELDS[0].aliases(), &["address"]);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct FieldInfo {
+    name: &'static str,
+    index: usize,
+    variant: Option<&'static str>,
+    doc: Option<&'static str>,
+    aliases: &'static [&'static str],
+    meta: &'static [(&'static str, MetaValue)],
+    groups: &'static [&'static str],
+    readable: bool,
+    writable: bool,
+    ty: &'static FieldType,
+}
+
+impl FieldInfo {
+    #[doc(hidden)]
+    pub const fn new(name: &'static str, index: usize, ty: &'static FieldType) -> Self {
+        FieldInfo {
+            name,
+            index,
+            variant: None,
+            doc: None,
+            aliases: &[],
+            meta: &[],
+            groups: &[],
+            readable: true,
+            writable: true,
+            ty,
+        }
+    }
+
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn with_variant(mut self, variant: &'static str) -> Self {
+        self.variant = Some(variant);
+        self
+    }
+
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn with_doc(mut self, doc: &'static str) -> Self {
+        self.doc = Some(doc);
+        self
+    }
+
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn with_aliases(mut self, aliases: &'static [&'static str]) -> Self {
+        self.aliases = aliases;
+        self
+    }
+
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn with_meta(mut self, meta: &'static [(&'static str, MetaValue)]) -> Self {
+        self.meta = meta;
+        self
+    }
+
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn with_groups(mut self, groups: &'static [&'static str]) -> Self {
+        self.groups = groups;
+        self
+    }
+
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn readonly(mut self) -> Self {
+        self.writable = false;
+        self
+    }
+
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn writeonly(mut self) -> Self {
+        self.readable = false;
+        self
+    }
+
+    /// Returns the name of the field.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the index of the field in
+    /// [`AnyFieldAccess::field_names`](crate::AnyFieldAccess::field_names).
+    ///
+    /// For enums, this is the index within the field's variant.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the name of the enum variant the field belongs to, or `None` for struct fields.
+    pub fn variant(&self) -> Option<&'static str> {
+        self.variant
+    }
+
+    /// Returns the doc comment of the field.
+    ///
+    /// The common indentation of all lines is removed, as well as leading and trailing empty
+    /// lines. Returns `None` if the field is not documented.
+    pub fn doc(&self) -> Option<&'static str> {
+        self.doc
+    }
+
+    /// Returns the aliases of the field.
+    pub fn aliases(&self) -> &'static [&'static str] {
+        self.aliases
+    }
+
+    /// Returns the metadata of the field as key-value pairs in declaration order.
+    ///
+    /// Metadata is attached to fields via `#[field_access(meta(key = value, ...))]`.
+    pub fn meta(&self) -> &'static [(&'static str, MetaValue)] {
+        self.meta
+    }
+
+    /// Returns the metadata value for `key`, or `None` if there is none.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use field_access::{FieldAccess, MetaValue};
+    ///
+    /// #[derive(FieldAccess)]
+    /// struct Server {
+    ///     #[field_access(meta(unit = "ms", restart))]
+    ///     timeout: u64,
+    /// }
+    ///
+    /// let timeout = &Server::FIELDS[0];
+    ///
+    /// assert_eq!(timeout.meta_value("unit"), Some(&MetaValue::Str("ms")));
+    /// assert_eq!(timeout.meta_value("restart"), Some(&MetaValue::Bool(true)));
+    /// assert_eq!(timeout.meta_value("min"), None);
+    /// ```
+    pub fn meta_value(&self, key: &str) -> Option<&'static MetaValue> {
+        self.meta
+            .iter()
+            .find(|(other, _)| *other == key)
+            .map(|(_, value)| value)
+    }
+
+    /// Returns the groups of the field.
+    ///
+    /// Fields are added to groups via `#[field_access(group = "...")]`.
+    pub fn groups(&self) -> &'static [&'static str] {
+        self.groups
+    }
+
+    /// Returns `true` if the field is a member of `group`.
+    pub fn in_group(&self, group: &str) -> bool {
+        self.groups.contains(&group)
+    }
+
+    /// Returns `true` if the field can be accessed immutably.
+    pub fn is_readable(&self) -> bool {
+        self.readable
+    }
+
+    /// Returns `true` if the field can be accessed mutably.
+    pub fn is_writable(&self) -> bool {
+        self.writable
+    }
+
+    /// Returns the type of the field.
+    pub fn ty(&self) -> &'static FieldType {
+        self.ty
+    }
+}
+
+/// A metadata value attached to a field via `#[field_access(meta(key = value))]`.
+///
+/// Values are literals of the corresponding type. A key without a value, as in
+/// `#[field_access(meta(key))]`, is equivalent to `key = true`.
+///
+/// # Example
+///
+/// ```
+/// use field_access::{FieldAccess, MetaValue};
+///
+/// #[derive(FieldAccess)]
+/// struct Server {
+///     #[field_access(meta(unit = "ms", min = -1, scale = 0.5, restart = true))]
+///     timeout: i64,
+/// }
+///
+/// let server = Server { timeout: 1000 };
+///
+/// assert_eq!(
+///     server.field_meta("timeout"),
+///     &[
+///         ("unit", MetaValue::Str("ms")),
+///         ("min", MetaValue::Int(-1)),
+///         ("scale", MetaValue::Float(0.5)),
+///         ("restart", MetaValue::Bool(true)),
+///     ]
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum MetaValue {
+    /// A string literal.
+    Str(&'static str),
+    /// A boolean literal.
+    Bool(bool),
+    /// An integer literal.
+    Int(i64),
+    /// A floating point literal.
+    Float(f64),
+}
+
+impl MetaValue {
+    /// Returns the string value, or `None` if the value is not a string.
+    pub fn as_str(&self) -> Option<&'static str> {
+        match *self {
+            MetaValue::Str(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the boolean value, or `None` if the value is not a boolean.
+    pub fn as_bool(&self) -> Option<bool> {
+        match *self {
+            MetaValue::Bool(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the integer value, or `None` if the value is not an integer.
+    pub fn as_int(&self) -> Option<i64> {
+        match *self {
+            MetaValue::Int(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the floating point value, or `None` if the value is not a number.
+    ///
+    /// Integers are converted to floating point numbers.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn as_float(&self) -> Option<f64> {
+        match *self {
+            MetaValue::Float(value) => Some(value),
+            MetaValue::Int(value) => Some(value as f64),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for MetaValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MetaValue::Str(value) => value.fmt(f),
+            MetaValue::Bool(value) => value.fmt(f),
+            MetaValue::Int(value) => value.fmt(f),
+            MetaValue::Float(value) => value.fmt(f),
+        }
+    }
+}
@@ -17,3 +17,276 @@ fn field_names() {
 
     assert_eq!(foo.field_names(), &["a", "b", "c"]);
 }
+
+#[test]
+fn tuple_struct() {
+    #[derive(FieldAccess)]
+    struct Foo(u8, &'static str);
+
+    let mut foo = Foo(1, "b");
+
+    assert_eq!(foo.field_names(), &["0", "1"]);
+    assert_eq!(foo.field("0").unwrap().as_u8(), Some(1));
+    assert_eq!(foo.field("1").unwrap().as_str(), Some("b"));
+    assert!(foo.field("2").is_none());
+
+    assert!(foo.field_mut("0").unwrap().set(42u8));
+    assert_eq!(foo.0, 42);
+}
+
+#[test]
+fn unit_struct() {
+    #[derive(FieldAccess)]
+    struct Foo;
+
+    let mut foo = Foo;
+
+    assert!(foo.field_names().is_empty());
+    assert!(foo.field("a").is_none());
+    assert!(foo.field_mut("a").is_none());
+    assert_eq!(foo.fields().count(), 0);
+}
+
+#[test]
+fn enums() {
+    #[derive(FieldAccess)]
+    enum Foo {
+        A { a: u8, field: &'static str },
+        B(u16, bool),
+        C,
+    }
+
+    let mut foo = Foo::A { a: 1, field: "b" };
+
+    assert_eq!(foo.variant_name(), Some("A"));
+    assert_eq!(foo.field_names(), &["a", "field"]);
+    assert_eq!(foo.field("a").unwrap().as_u8(), Some(1));
+    assert_eq!(foo.field("field").unwrap().as_str(), Some("b"));
+    assert!(foo.field("0").is_none());
+
+    assert!(foo.field_mut("a").unwrap().set(42u8));
+    assert!(matches!(foo, Foo::A { a: 42, .. }));
+
+    let foo = Foo::B(2, true);
+
+    assert_eq!(foo.variant_name(), Some("B"));
+    assert_eq!(foo.field_names(), &["0", "1"]);
+    assert_eq!(foo.field("0").unwrap().as_u16(), Some(2));
+    assert_eq!(foo.field("1").unwrap().as_bool(), Some(true));
+    assert!(foo.field("a").is_none());
+
+    let foo = Foo::C;
+
+    assert_eq!(foo.variant_name(), Some("C"));
+    assert!(foo.field_names().is_empty());
+    assert_eq!(foo.fields().count(), 0);
+}
+
+#[test]
+fn struct_variant_name() {
+    #[derive(FieldAccess)]
+    struct Foo {
+        a: u8,
+    }
+
+    assert_eq!(Foo { a: 1 }.variant_name(), None);
+}
+
+#[test]
+fn generic_struct() {
+    #[derive(FieldAccess)]
+    struct Wrapper<T> {
+        inner: T,
+    }
+
+    let mut wrapper = Wrapper { inner: 1u8 };
+
+    assert_eq!(wrapper.field("inner").unwrap().as_u8(), Some(1));
+    assert!(wrapper.field_mut("inner").unwrap().set(2u8));
+    assert_eq!(wrapper.inner, 2);
+
+    fn names<T: 'static>(wrapper: &Wrapper<T>) -> &'static [&'static str] {
+        wrapper.field_names()
+    }
+
+    assert_eq!(names(&Wrapper { inner: "a" }), &["inner"]);
+}
+
+#[test]
+fn rename() {
+    #[derive(FieldAccess)]
+    struct Foo {
+        #[field_access(rename = "max-retries")]
+        max_retries: u8,
+        r#type: &'static str,
+        #[field_access(rename = "ref")]
+        r#ref: bool,
+    }
+
+    let mut foo = Foo {
+        max_retries: 1,
+        r#type: "a",
+        r#ref: true,
+    };
+
+    assert_eq!(foo.field_names(), &["max-retries", "type", "ref"]);
+    assert_eq!(foo.field("max-retries").unwrap().as_u8(), Some(1));
+    assert_eq!(foo.field("type").unwrap().as_str(), Some("a"));
+    assert!(foo.field("max_retries").is_none());
+    assert!(foo.field("r#type").is_none());
+
+    assert!(foo.field_mut("ref").unwrap().set(false));
+    assert!(!foo.r#ref);
+}
+
+#[test]
+fn rename_all() {
+    macro_rules! assert_rename_all {
+        ($rule:literal, $expected:expr) => {{
+            #[derive(FieldAccess, Default)]
+            #[field_access(rename_all = $rule)]
+            struct Foo {
+                max_retries: u8,
+                r#type: u8,
+                #[field_access(rename = "keep_me")]
+                renamed: u8,
+            }
+
+            assert_eq!(Foo::default().field_names(), &$expected, $rule);
+        }};
+    }
+
+    assert_rename_all!("lowercase", ["max_retries", "type", "keep_me"]);
+    assert_rename_all!("UPPERCASE", ["MAX_RETRIES", "TYPE", "keep_me"]);
+    assert_rename_all!("PascalCase", ["MaxRetries", "Type", "keep_me"]);
+    assert_rename_all!("camelCase", ["maxRetries", "type", "keep_me"]);
+    assert_rename_all!("snake_case", ["max_retries", "type", "keep_me"]);
+    assert_rename_all!("SCREAMING_SNAKE_CASE", ["MAX_RETRIES", "TYPE", "keep_me"]);
+    assert_rename_all!("kebab-case", ["max-retries", "type", "keep_me"]);
+    assert_rename_all!("SCREAMING-KEBAB-CASE", ["MAX-RETRIES", "TYPE", "keep_me"]);
+}
+
+#[test]
+fn rename_all_enum() {
+    #[derive(FieldAccess)]
+    #[field_access(rename_all = "camelCase")]
+    enum Foo {
+        A { max_retries: u8 },
+        B(u8),
+    }
+
+    let foo = Foo::A { max_retries: 1 };
+
+    assert_eq!(foo.field_names(), &["maxRetries"]);
+    assert_eq!(foo.field("maxRetries").unwrap().as_u8(), Some(1));
+    assert_eq!(foo.variant_name(), Some("A"));
+    assert_eq!(Foo::B(1).field_names(), &["0"]);
+}
+
+#[test]
+fn skip() {
+    #[derive(FieldAccess)]
+    struct Foo {
+        a: u8,
+        #[field_access(skip)]
+        secret: &'static str,
+        #[field_access(skip, rename = "a")]
+        b: u8,
+    }
+
+    let mut foo = Foo {
+        a: 1,
+        secret: "secret",
+        b: 2,
+    };
+
+    assert_eq!(foo.field_names(), &["a"]);
+    assert!(foo.field("secret").is_none());
+    assert!(foo.field_mut("secret").is_none());
+    assert!(foo.field("b").is_none());
+    assert_eq!(foo.field("a").unwrap().as_u8(), Some(1));
+    assert_eq!(foo.fields().count(), 1);
+    assert_eq!((foo.secret, foo.b), ("secret", 2));
+}
+
+#[test]
+fn readonly_and_writeonly() {
+    #[derive(FieldAccess)]
+    struct Foo {
+        #[field_access(readonly)]
+        id: u64,
+        #[field_access(writeonly)]
+        password: &'static str,
+        name: &'static str,
+    }
+
+    let mut foo = Foo {
+        id: 1,
+        password: "secret",
+        name: "foo",
+    };
+
+    assert_eq!(foo.field_names(), &["id", "password", "name"]);
+
+    assert_eq!(foo.field("id").unwrap().as_u64(), Some(1));
+    assert!(foo.field_mut("id").is_none());
+
+    assert!(foo.field("password").is_none());
+    assert!(foo.field_mut("password").unwrap().set("changed"));
+    assert_eq!(foo.password, "changed");
+
+    let fields: Vec<_> = foo.fields().map(|(name, _)| name).collect();
+    assert_eq!(fields, &["id", "name"]);
+    assert_eq!(foo.fields().len(), 2);
+
+    let fields: Vec<_> = foo.fields().rev().map(|(name, _)| name).collect();
+    assert_eq!(fields, &["name", "id"]);
+}
+
+#[test]
+fn aliases() {
+    #[derive(FieldAccess)]
+    struct Foo {
+        #[field_access(alias = "old_name", alias = "older_name")]
+        name: &'static str,
+        #[field_access(rename = "b", alias = "a")]
+        c: u8,
+    }
+
+    let mut foo = Foo { name: "foo", c: 1 };
+
+    assert_eq!(foo.field_names(), &["name", "b"]);
+    assert_eq!(foo.field_aliases("name"), &["old_name", "older_name"]);
+    assert_eq!(foo.field_aliases("b"), &["a"]);
+    assert!(foo.field_aliases("old_name").is_empty());
+    assert!(foo.field_aliases("missing").is_empty());
+
+    assert_eq!(foo.field("old_name").unwrap().as_str(), Some("foo"));
+    assert_eq!(foo.field("older_name").unwrap().as_str(), Some("foo"));
+    assert!(foo.field_mut("a").unwrap().set(2u8));
+    assert_eq!(foo.c, 2);
+    assert!(foo.field("c").is_none());
+    assert_eq!(foo.fields().count(), 2);
+}
+
+#[test]
+fn enum_aliases() {
+    #[derive(FieldAccess)]
+    enum Foo {
+        A {
+            #[field_access(alias = "x")]
+            a: u8,
+        },
+        B {
+            a: u8,
+        },
+    }
+
+    let foo = Foo::A { a: 1 };
+    assert_eq!(foo.field_aliases("a"), &["x"]);
+    assert!(foo.field("x").is_some());
+
+    let foo = Foo::B { a: 1 };
+    assert!(foo.field_aliases("a").is_empty());
+    assert!(foo.field("x").is_none());
+}
@@ -0,0 +1,166 @@
+use field_access::{BorrowedFieldAccess, FieldAccess};
+
+#[derive(FieldAccess)]
+struct Message<'a> {
+    id: u32,
+    name: &'a str,
+    payload: &'a [u8],
+    tags: Vec<&'static str>,
+}
+
+#[test]
+fn field_names() {
+    let message = Message {
+        id: 1,
+        name: "foo",
+        payload: &[],
+        tags: Vec::new(),
+    };
+
+    assert_eq!(message.field_names(), &["id", "name", "payload", "tags"]);
+    assert_eq!(message.variant_name(), None);
+}
+
+#[test]
+fn borrowed_fields_outlive_the_struct() {
+    let input = String::from("hello world");
+    let bytes = input.as_bytes();
+
+    let (name, payload) = {
+        let message = Message {
+            id: 1,
+            name: &input[..5],
+            payload: &bytes[6..],
+            tags: vec!["a"],
+        };
+
+        let name = message.field("name").unwrap().as_str().unwrap();
+        let payload = message.field("payload").unwrap().as_slice::<u8>().unwrap();
+        (name, payload)
+    };
+
+    assert_eq!(name, "hello");
+    assert_eq!(payload, b"world");
+}
+
+#[test]
+fn owned_and_borrowed_fields() {
+    let message = Message {
+        id: 1,
+        name: "foo",
+        payload: &[1, 2],
+        tags: vec!["a"],
+    };
+
+    let id = message.field("id").unwrap();
+    assert!(id.is::<u32>());
+    assert!(!id.is_ref::<u32>());
+    assert_eq!(id.get::<u32>(), Some(&1));
+    assert_eq!(id.get_ref::<u32>(), None);
+
+    let name = message.field("name").unwrap();
+    assert!(name.is_ref::<str>());
+    assert!(!name.is::<&'static str>());
+    assert_eq!(name.get::<&'static str>(), None);
+    assert_eq!(name.as_slice::<u8>(), None);
+
+    let tags = message.field("tags").unwrap();
+    assert_eq!(tags.get::<Vec<&'static str>>(), Some(&vec!["a"]));
+
+    assert!(message.field("missing").is_none());
+}
+
+#[test]
+fn field_mut() {
+    let input = String::from("foo bar");
+    let mut message = Message {
+        id: 1,
+        name: &input[..3],
+        payload: &[],
+        tags: Vec::new(),
+    };
+
+    let mut id = message.field_mut("id").unwrap();
+    assert_eq!(id.replace(2u32), Some(1));
+    assert!(!id.set("foo"));
+
+    let mut name = message.field_mut("name").unwrap();
+    assert_eq!(name.get_ref::<str>(), Some("foo"));
+    assert!(name.set_ref(&input[4..]));
+    assert!(!name.set_ref(&[1u8][..]));
+    assert!(name.get_mut::<&'static str>().is_none());
+
+    assert_eq!(message.id, 2);
+    assert_eq!(message.name, "bar");
+}
+
+#[test]
+fn enums() {
+    #[derive(FieldAccess)]
+    enum Token<'a> {
+        Ident(&'a str),
+        Number { value: i64 },
+    }
+
+    let input = String::from("foo");
+    let token = Token::Ident(&input);
+
+    assert_eq!(token.variant_name(), Some("Ident"));
+    assert_eq!(token.field("0").unwrap().as_str(), Some("foo"));
+
+    let token = Token::Number { value: 42 };
+
+    assert_eq!(token.variant_name(), Some("Number"));
+    assert_eq!(token.field_names(), &["value"]);
+    assert_eq!(token.field("value").unwrap().get::<i64>(), Some(&42));
+}
+
+#[test]
+fn skip_non_static_field() {
+    use std::borrow::Cow;
+
+    #[derive(FieldAccess)]
+    struct Foo<'a> {
+        a: &'a str,
+        #[field_access(skip)]
+        b: Cow<'a, str>,
+    }
+
+    let foo = Foo {
+        a: "a",
+        b: Cow::Borrowed("b"),
+    };
+
+    assert_eq!(foo.field_names(), &["a"]);
+    assert!(foo.field("b").is_none());
+    assert_eq!(foo.b, "b");
+}
+
+#[test]
+fn static_and_borrowed_references() {
+    #[derive(FieldAccess)]
+    struct Msg<'a> {
+        name: &'static str,
+        body: &'a str,
+    }
+
+    let input = String::from("body");
+    let mut msg = Msg {
+        name: "name",
+        body: &input,
+    };
+
+    let name = msg.field("name").unwrap();
+    assert!(name.is::<&'static str>());
+    assert!(!name.is_ref::<str>());
+    assert_eq!(name.get::<&'static str>(), Some(&"name"));
+
+    let body = msg.field("body").unwrap();
+    assert!(body.is_ref::<str>());
+    assert_eq!(body.as_str(), Some("body"));
+
+    assert!(msg.field_mut("name").unwrap().set("other"));
+    assert!(msg.field_mut("body").unwrap().set_ref(&input[..2]));
+    assert_eq!(msg.name, "other");
+    assert_eq!(msg.body, "bo");
+}
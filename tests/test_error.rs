@@ -0,0 +1,230 @@
+use core::any::TypeId;
+use field_access::{FieldAccess, FieldError};
+
+#[test]
+fn no_such_field() {
+    #[derive(FieldAccess)]
+    struct Foo {
+        port: u16,
+    }
+
+    let mut foo = Foo { port: 80 };
+
+    assert_eq!(
+        foo.try_field("prot").unwrap_err(),
+        FieldError::NoSuchField {
+            name: "prot",
+            suggestion: Some("port")
+        }
+    );
+    assert_eq!(
+        foo.try_field_mut("timeout").unwrap_err(),
+        FieldError::NoSuchField {
+            name: "timeout",
+            suggestion: None
+        }
+    );
+}
+
+#[test]
+fn read_only() {
+    #[derive(FieldAccess)]
+    struct Foo {
+        #[field_access(readonly)]
+        id: u32,
+    }
+
+    let mut foo = Foo { id: 1 };
+
+    assert!(foo.try_field("id").is_ok());
+    assert_eq!(
+        foo.try_field_mut("id").unwrap_err(),
+        FieldError::ReadOnly { name: "id" }
+    );
+}
+
+#[test]
+fn write_only() {
+    #[derive(FieldAccess)]
+    struct Foo {
+        #[field_access(writeonly, alias = "key")]
+        secret: &'static str,
+    }
+
+    let mut foo = Foo { secret: "" };
+
+    assert!(foo.try_field_mut("secret").is_ok());
+    assert_eq!(
+        foo.try_field("secret").unwrap_err(),
+        FieldError::WriteOnly { name: "secret" }
+    );
+    assert_eq!(
+        foo.try_field("key").unwrap_err(),
+        FieldError::WriteOnly { name: "key" }
+    );
+}
+
+#[test]
+fn flattened() {
+    #[derive(FieldAccess)]
+    struct Limits {
+        #[field_access(writeonly)]
+        max_connections: u32,
+    }
+
+    #[derive(FieldAccess)]
+    struct Foo {
+        #[field_access(flatten)]
+        limits: Limits,
+    }
+
+    let mut foo = Foo {
+        limits: Limits { max_connections: 1 },
+    };
+
+    assert_eq!(
+        foo.try_field("max_connections").unwrap_err(),
+        FieldError::WriteOnly {
+            name: "max_connections"
+        }
+    );
+    assert_eq!(
+        foo.try_field_mut("limits").unwrap_err(),
+        FieldError::NoSuchField {
+            name: "limits",
+            suggestion: None
+        }
+    );
+    assert_eq!(
+        foo.try_field_mut("max_conections").unwrap_err(),
+        FieldError::NoSuchField {
+            name: "max_conections",
+            suggestion: Some("max_connections")
+        }
+    );
+}
+
+#[test]
+fn type_mismatch() {
+    #[derive(FieldAccess)]
+    struct Foo {
+        port: u16,
+    }
+
+    let mut foo = Foo { port: 80 };
+    let mut field = foo.try_field_mut("port").unwrap();
+    let err = field.try_set("443").unwrap_err();
+
+    assert_eq!(
+        err,
+        FieldError::TypeMismatch {
+            expected: "&str",
+            actual: TypeId::of::<u16>(),
+            actual_name: Some("u16")
+        }
+    );
+    assert!(matches!(
+        field.try_replace(443u32),
+        Err(FieldError::TypeMismatch {
+            expected: "u32",
+            ..
+        })
+    ));
+    assert!(matches!(
+        field.try_get::<u32>(),
+        Err(FieldError::TypeMismatch {
+            expected: "u32",
+            ..
+        })
+    ));
+    assert!(matches!(
+        field.try_get_mut::<u32>(),
+        Err(FieldError::TypeMismatch {
+            expected: "u32",
+            ..
+        })
+    ));
+    assert_eq!(foo.port, 80);
+}
+
+#[test]
+fn display() {
+    assert_eq!(
+        FieldError::NoSuchField {
+            name: "a",
+            suggestion: None
+        }
+        .to_string(),
+        "no such field `a`"
+    );
+    assert_eq!(
+        FieldError::NoSuchField {
+            name: "prot",
+            suggestion: Some("port")
+        }
+        .to_string(),
+        "no such field `prot`, did you mean `port`?"
+    );
+    assert_eq!(
+        FieldError::ReadOnly { name: "a" }.to_string(),
+        "field `a` is read-only"
+    );
+    assert_eq!(
+        FieldError::WriteOnly { name: "a" }.to_string(),
+        "field `a` is write-only"
+    );
+    assert_eq!(
+        FieldError::TypeMismatch {
+            expected: "&str",
+            actual: TypeId::of::<u16>(),
+            actual_name: Some("u16")
+        }
+        .to_string(),
+        "type mismatch: expected a value of type `&str`, found `u16`"
+    );
+    assert_eq!(
+        FieldError::TypeMismatch {
+            expected: "&str",
+            actual: TypeId::of::<u16>(),
+            actual_name: None
+        }
+        .to_string(),
+        "type mismatch: expected a value of type `&str`"
+    );
+}
+
+#[test]
+fn suggest_field() {
+    #[derive(FieldAccess)]
+    struct Limits {
+        max_connections: u32,
+    }
+
+    #[derive(FieldAccess)]
+    struct Foo {
+        port: u16,
+        id: u32,
+        #[field_access(writeonly)]
+        secret: &'static str,
+        #[field_access(flatten)]
+        limits: Limits,
+    }
+
+    let foo = Foo {
+        port: 80,
+        id: 1,
+        secret: "",
+        limits: Limits { max_connections: 1 },
+    };
+
+    assert_eq!(foo.suggest_field("port"), Some("port"));
+    assert_eq!(foo.suggest_field("prot"), Some("port"));
+    assert_eq!(foo.suggest_field("Port"), Some("port"));
+    assert_eq!(foo.suggest_field("pot"), Some("port"));
+    assert_eq!(foo.suggest_field("ID"), Some("id"));
+    assert_eq!(foo.suggest_field("secrte"), Some("secret"));
+    assert_eq!(foo.suggest_field("maxconnection"), Some("max_connections"));
+    assert_eq!(foo.suggest_field("limits"), None);
+    assert_eq!(foo.suggest_field("x"), None);
+    assert_eq!(foo.suggest_field(""), None);
+}
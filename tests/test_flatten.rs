@@ -0,0 +1,181 @@
+use field_access::{AnyFieldAccess, FieldAccess};
+
+#[derive(FieldAccess, Default)]
+struct Tls {
+    #[field_access(alias = "cert")]
+    cert_path: &'static str,
+    #[field_access(readonly)]
+    verify: bool,
+}
+
+#[derive(FieldAccess, Default)]
+struct Limits {
+    max_connections: u32,
+}
+
+#[derive(FieldAccess, Default)]
+struct Server {
+    #[field_access(flatten)]
+    tls: Tls,
+    port: u16,
+    #[field_access(flatten, readonly)]
+    limits: Limits,
+}
+
+#[derive(FieldAccess, Default)]
+struct Config {
+    #[field_access(flatten)]
+    server: Server,
+    name: &'static str,
+}
+
+#[test]
+fn field_names() {
+    let server = Server::default();
+
+    assert_eq!(server.field_names(), &["port"]);
+
+    let names: Vec<_> = server.all_field_names().collect();
+    assert_eq!(names, &["port", "cert_path", "verify", "max_connections"]);
+
+    let names: Vec<_> = server.all_field_names().rev().collect();
+    assert_eq!(names, &["max_connections", "verify", "cert_path", "port"]);
+    assert_eq!(server.all_field_names().len(), 4);
+}
+
+#[test]
+fn nested_field_names() {
+    let config = Config::default();
+
+    let names: Vec<_> = config.all_field_names().collect();
+    assert_eq!(
+        names,
+        &["name", "port", "cert_path", "verify", "max_connections"]
+    );
+}
+
+#[test]
+fn field_lookup() {
+    let mut config = Config::default();
+
+    assert!(config.field_mut("cert_path").unwrap().set("cert.pem"));
+    assert_eq!(config.server.tls.cert_path, "cert.pem");
+    assert_eq!(config.field("cert").unwrap().as_str(), Some("cert.pem"));
+    assert_eq!(config.field_aliases("cert_path"), &["cert"]);
+
+    assert!(config.field_mut("port").unwrap().set(443u16));
+    assert_eq!(config.server.port, 443);
+
+    assert!(config.field("verify").is_some());
+    assert!(config.field_mut("verify").is_none());
+
+    assert!(config.field("max_connections").is_some());
+    assert!(config.field_mut("max_connections").is_none());
+
+    assert!(config.field("server").is_none());
+    assert!(config.field("tls").is_none());
+    assert!(config.field("missing").is_none());
+}
+
+#[test]
+fn fields() {
+    let config = Config::default();
+
+    let names: Vec<_> = config.fields().map(|(name, _)| name).collect();
+    assert_eq!(
+        names,
+        &["name", "port", "cert_path", "verify", "max_connections"]
+    );
+
+    let mut fields = config.fields();
+    assert_eq!(fields.len(), 5);
+    assert_eq!(fields.next_back().unwrap().0, "max_connections");
+    assert_eq!(fields.nth(2).unwrap().0, "cert_path");
+    assert_eq!(fields.len(), 1);
+}
+
+#[test]
+fn enums() {
+    #[derive(FieldAccess)]
+    enum Listener {
+        Tcp {
+            #[field_access(flatten)]
+            tls: Tls,
+            port: u16,
+        },
+        Unix {
+            path: &'static str,
+        },
+    }
+
+    let listener = Listener::Tcp {
+        tls: Tls::default(),
+        port: 1,
+    };
+
+    assert!(listener.field("cert_path").is_some());
+    assert!(listener.flattened(0).is_some());
+
+    let listener = Listener::Unix { path: "/tmp/sock" };
+
+    assert!(listener.field("cert_path").is_none());
+    assert!(listener.flattened(0).is_none());
+    assert_eq!(listener.all_field_names().collect::<Vec<_>>(), &["path"]);
+}
+
+#[test]
+fn shadowed_fields() {
+    #[derive(FieldAccess, Default)]
+    struct Inner {
+        a: u8,
+        #[field_access(readonly)]
+        b: u8,
+        c: u8,
+    }
+
+    #[derive(FieldAccess, Default)]
+    struct Other {
+        b: u16,
+        c: u16,
+        d: u16,
+    }
+
+    #[derive(FieldAccess, Default)]
+    struct Outer {
+        #[field_access(writeonly)]
+        a: u32,
+        #[field_access(flatten)]
+        inner: Inner,
+        #[field_access(flatten)]
+        other: Other,
+    }
+
+    let mut outer = Outer::default();
+
+    let names: Vec<_> = outer.all_field_names().collect();
+    assert_eq!(names, &["a", "b", "c", "d"]);
+    assert_eq!(outer.all_field_names().len(), 4);
+    assert_eq!(
+        outer.all_field_names().rev().collect::<Vec<_>>(),
+        &["d", "c", "b", "a"]
+    );
+
+    let fields: Vec<_> = outer
+        .fields()
+        .map(|(name, field)| (name, field.type_name()))
+        .collect();
+    assert_eq!(
+        fields,
+        &[("b", Some("u8")), ("c", Some("u8")), ("d", Some("u16"))]
+    );
+    assert_eq!(outer.fields().len(), 3);
+
+    assert!(outer.field("a").is_none());
+    assert!(outer.field_mut("a").unwrap().set(1u32));
+    assert_eq!(outer.field("b").unwrap().type_name(), Some("u8"));
+    assert!(outer.field_mut("b").is_none());
+    assert!(outer.field_as_any_mut("b").is_none());
+    assert_eq!(outer.field("c").unwrap().as_u8(), Some(0));
+    assert_eq!(outer.field_type("b").unwrap().ty(), "u8");
+    assert_eq!(outer.field("d").unwrap().as_u16(), Some(0));
+}
@@ -0,0 +1,321 @@
+use core::any::{Any, TypeId};
+use core::cell::Cell;
+use field_access::{AnyFieldAccess, FieldAccess};
+
+#[test]
+fn field_index() {
+    #[derive(FieldAccess)]
+    struct Foo {
+        #[field_access(alias = "b")]
+        a: u8,
+        #[field_access(readonly)]
+        c: u64,
+        #[field_access(writeonly)]
+        d: &'static str,
+    }
+
+    let foo = Foo { a: 1, c: 2, d: "d" };
+
+    assert_eq!(foo.field_index("a"), Some(0));
+    assert_eq!(foo.field_index("b"), Some(0));
+    assert_eq!(foo.field_index("c"), Some(1));
+    assert_eq!(foo.field_index("d"), Some(2));
+    assert_eq!(foo.field_index("e"), None);
+
+    for (index, name) in foo.field_names().iter().enumerate() {
+        assert_eq!(foo.field_index(name), Some(index));
+        assert_eq!(Foo::FIELDS[index].index(), index);
+    }
+}
+
+#[test]
+fn enum_field_index() {
+    #[derive(FieldAccess)]
+    enum Foo {
+        A { a: f64 },
+        B(u32, u32),
+        C,
+    }
+
+    assert_eq!(Foo::B(1, 2).field_index("1"), Some(1));
+    assert_eq!(Foo::B(1, 2).field_index("a"), None);
+    assert_eq!(Foo::A { a: 1.0 }.field_index("a"), Some(0));
+    assert_eq!(Foo::C.field_index("0"), None);
+}
+
+#[test]
+fn field_at() {
+    #[derive(FieldAccess)]
+    struct Foo {
+        a: &'static str,
+        #[field_access(readonly)]
+        b: u64,
+        #[field_access(writeonly)]
+        c: &'static str,
+        d: u16,
+    }
+
+    let mut foo = Foo {
+        a: "a",
+        b: 1,
+        c: "",
+        d: 80,
+    };
+
+    assert_eq!(foo.field_at(0).unwrap().downcast_ref::<&str>(), Some(&"a"));
+    assert_eq!(foo.field_at(1).unwrap().downcast_ref::<u64>(), Some(&1));
+    assert!(foo.field_at(2).is_none());
+    assert_eq!(foo.field_at(3).unwrap().downcast_ref::<u16>(), Some(&80));
+    assert!(foo.field_at(4).is_none());
+
+    assert!(foo.field_at_mut(1).is_none());
+    *foo.field_at_mut(2).unwrap().downcast_mut::<&str>().unwrap() = "c";
+    let index = foo.field_index("d").unwrap();
+    *foo.field_at_mut(index)
+        .unwrap()
+        .downcast_mut::<u16>()
+        .unwrap() = 443;
+    assert!(foo.field_at_mut(4).is_none());
+    assert_eq!(foo.c, "c");
+    assert_eq!(foo.d, 443);
+}
+
+#[test]
+fn enum_field_at() {
+    #[derive(FieldAccess)]
+    enum Foo {
+        A { a: f64 },
+        B(u32, #[field_access(readonly)] u32),
+        C,
+    }
+
+    let mut foo = Foo::B(1, 2);
+    assert_eq!(foo.field_at(1).unwrap().downcast_ref::<u32>(), Some(&2));
+    assert!(foo.field_at_mut(1).is_none());
+    assert!(foo.field_at_mut(0).is_some());
+    assert!(Foo::C.field_at(0).is_none());
+    assert_eq!(
+        Foo::A { a: 1.0 }.field_at(0).unwrap().downcast_ref::<f64>(),
+        Some(&1.0)
+    );
+}
+
+#[test]
+fn skipped_fields() {
+    #[derive(FieldAccess)]
+    struct Foo {
+        #[field_access(skip)]
+        _a: u8,
+        b: u8,
+        #[field_access(skip)]
+        _c: u8,
+        d: u8,
+    }
+
+    let foo = Foo {
+        _a: 1,
+        b: 2,
+        _c: 3,
+        d: 4,
+    };
+
+    assert_eq!(foo.field_names(), &["b", "d"]);
+    assert_eq!(foo.field_index("_a"), None);
+    assert_eq!(foo.field_index("_c"), None);
+
+    // Skipped fields don't take up an index.
+    for (index, name) in foo.field_names().iter().enumerate() {
+        assert_eq!(foo.field_index(name), Some(index));
+        assert_eq!(
+            foo.field_at(index).unwrap().downcast_ref::<u8>(),
+            foo.field(name).unwrap().get::<u8>()
+        );
+        assert_eq!(foo.field_info_at(index).unwrap().name(), *name);
+        assert_eq!(foo.field_info_at(index).unwrap().index(), index);
+    }
+    assert!(foo.field_at(2).is_none());
+    assert!(foo.field_info_at(2).is_none());
+}
+
+#[test]
+fn flattened_enum_fields() {
+    #[derive(FieldAccess)]
+    struct Tls {
+        cert: &'static str,
+    }
+
+    #[derive(FieldAccess)]
+    enum Foo {
+        A {
+            #[field_access(flatten)]
+            tls: Tls,
+            port: u16,
+        },
+        B {
+            #[field_access(skip)]
+            _skipped: bool,
+            path: &'static str,
+            #[field_access(flatten)]
+            tls: Tls,
+        },
+    }
+
+    let a = Foo::A {
+        tls: Tls { cert: "a.pem" },
+        port: 443,
+    };
+    let b = Foo::B {
+        _skipped: false,
+        path: "/run/b",
+        tls: Tls { cert: "b.pem" },
+    };
+
+    assert_eq!(a.field_names(), &["port"]);
+    assert_eq!(b.field_names(), &["path"]);
+
+    // Flattened fields are accessible by name but have no index of their own.
+    for foo in [&a, &b] {
+        assert_eq!(foo.field_index("cert"), None);
+        assert_eq!(foo.field_index("tls"), None);
+        assert!(foo.field("cert").is_some());
+        assert!(foo.field_at(1).is_none());
+        assert!(foo.field_info_at(1).is_none());
+
+        let name = foo.field_names()[0];
+        let info = foo.field_info_at(0).unwrap();
+        assert_eq!(foo.field_index(name), Some(0));
+        assert_eq!(info.name(), name);
+        assert_eq!(info.variant(), foo.variant_name());
+        assert_eq!(
+            foo.field_at(0).unwrap().type_id(),
+            foo.field(name).unwrap().type_id()
+        );
+    }
+
+    assert_eq!(a.field_at(0).unwrap().downcast_ref::<u16>(), Some(&443));
+    assert_eq!(
+        b.field_at(0).unwrap().downcast_ref::<&str>(),
+        Some(&"/run/b")
+    );
+}
+
+#[test]
+fn provided_methods() {
+    // A manual implementation which relies on the provided methods.
+    struct Foo {
+        a: u8,
+        b: u16,
+    }
+
+    impl AnyFieldAccess for Foo {
+        fn field_as_any(&self, field: &str) -> Option<&dyn Any> {
+            match field {
+                "a" => Some(&self.a),
+                "b" => Some(&self.b),
+                _ => None,
+            }
+        }
+
+        fn field_as_any_mut(&mut self, field: &str) -> Option<&mut dyn Any> {
+            match field {
+                "a" => Some(&mut self.a),
+                _ => None,
+            }
+        }
+
+        fn field_names(&self) -> &'static [&'static str] {
+            &["a", "b"]
+        }
+
+        fn field_aliases(&self, field: &str) -> &'static [&'static str] {
+            match field {
+                "b" => &["c"],
+                _ => &[],
+            }
+        }
+    }
+
+    let mut foo = Foo { a: 1, b: 2 };
+
+    assert_eq!(foo.field_index("b"), Some(1));
+    assert_eq!(foo.field_index("c"), Some(1));
+    assert_eq!(foo.field_index("d"), None);
+    assert_eq!(foo.field_at(1).unwrap().downcast_ref::<u16>(), Some(&2));
+    assert!(foo.field_at(2).is_none());
+    assert!(foo.field_at_mut(0).is_some());
+    assert!(foo.field_at_mut(1).is_none());
+    assert_eq!(foo.field("b").unwrap().as_u16(), Some(2));
+    assert!(foo.field("b").unwrap().as_field_access().is_none());
+    assert!(foo.field_mut("a").is_some());
+    assert!(foo.field_mut("b").is_none());
+
+    let fields: Vec<_> = foo
+        .fields()
+        .map(|(name, field)| (name, field.type_id()))
+        .collect();
+    assert_eq!(
+        fields,
+        [("a", TypeId::of::<u8>()), ("b", TypeId::of::<u16>())]
+    );
+    assert_eq!(foo.fields().len(), 2);
+}
+
+#[test]
+fn fields_len() {
+    #[derive(FieldAccess)]
+    struct Foo {
+        a: u8,
+        #[field_access(writeonly)]
+        b: u8,
+        c: u8,
+    }
+
+    let foo = Foo { a: 1, b: 2, c: 3 };
+    let mut fields = foo.fields();
+
+    assert_eq!(fields.len(), 2);
+    assert_eq!(fields.next().map(|(name, _)| name), Some("a"));
+    assert_eq!(fields.len(), 1);
+    assert_eq!(fields.next_back().map(|(name, _)| name), Some("c"));
+    assert_eq!(fields.len(), 0);
+    assert!(fields.next().is_none());
+}
+
+#[test]
+fn fields_lookups() {
+    // Counts the lookups of a manual implementation.
+    struct Foo {
+        a: u8,
+        b: u8,
+        lookups: Cell<usize>,
+    }
+
+    impl AnyFieldAccess for Foo {
+        fn field_as_any(&self, field: &str) -> Option<&dyn Any> {
+            self.lookups.set(self.lookups.get() + 1);
+
+            match field {
+                "a" => Some(&self.a),
+                "b" => Some(&self.b),
+                _ => None,
+            }
+        }
+
+        fn field_as_any_mut(&mut self, _field: &str) -> Option<&mut dyn Any> {
+            None
+        }
+
+        fn field_names(&self) -> &'static [&'static str] {
+            &["a", "b"]
+        }
+    }
+
+    let foo = Foo {
+        a: 1,
+        b: 2,
+        lookups: Cell::new(0),
+    };
+
+    assert_eq!(foo.fields().count(), 2);
+    assert_eq!(foo.lookups.get(), 2);
+}
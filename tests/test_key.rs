@@ -0,0 +1,165 @@
+use field_access::{AnyFieldAccess, FieldAccess, FieldKey, ReadOnly, ReadWrite, WriteOnly};
+
+#[test]
+fn key_types() {
+    #[derive(FieldAccess)]
+    #[field_access(keys)]
+    struct Foo {
+        a: String,
+        #[field_access(readonly)]
+        b: u64,
+        #[field_access(writeonly)]
+        c: &'static str,
+        #[field_access(skip)]
+        _d: bool,
+    }
+
+    let _: FieldKey<Foo, String, ReadWrite> = Foo::key_a();
+    let _: FieldKey<Foo, String> = Foo::key_a();
+    let _: FieldKey<Foo, u64, ReadOnly> = Foo::key_b();
+    let _: FieldKey<Foo, &'static str, WriteOnly> = Foo::key_c();
+}
+
+#[test]
+fn get_key() {
+    #[derive(FieldAccess)]
+    #[field_access(keys)]
+    struct Foo {
+        a: String,
+        #[field_access(readonly)]
+        b: u64,
+        #[field_access(writeonly)]
+        c: &'static str,
+    }
+
+    let mut foo = Foo {
+        a: "a".into(),
+        b: 1,
+        c: "",
+    };
+
+    assert_eq!(foo.get_key(Foo::key_a()), "a");
+    assert_eq!(foo.get_key(Foo::key_b()), &1);
+    assert_eq!(Foo::key_a().get(&foo), "a");
+
+    foo.get_key_mut(Foo::key_a()).push('b');
+    *foo.get_key_mut(Foo::key_c()) = "c";
+    Foo::key_a().get_mut(&mut foo).push('c');
+
+    assert_eq!(foo.a, "abc");
+    assert_eq!(foo.c, "c");
+}
+
+#[test]
+fn generic_keys() {
+    #[derive(FieldAccess)]
+    #[field_access(keys)]
+    struct Foo<T> {
+        a: T,
+        #[field_access(readonly)]
+        b: Vec<T>,
+    }
+
+    #[derive(FieldAccess)]
+    #[field_access(keys)]
+    struct Bar<T>(T, #[field_access(readonly)] Vec<T>);
+
+    let _: FieldKey<Foo<u8>, u8> = Foo::<u8>::key_a();
+    let _: FieldKey<Foo<&str>, Vec<&str>, ReadOnly> = Foo::<&str>::key_b();
+    let _: FieldKey<Bar<u8>, u8> = Bar::<u8>::key_0();
+    let _: FieldKey<Bar<u8>, Vec<u8>, ReadOnly> = Bar::<u8>::key_1();
+
+    let mut foo = Foo {
+        a: 1u8,
+        b: vec![2, 3],
+    };
+    *foo.get_key_mut(Foo::key_a()) += 1;
+    assert_eq!(foo.get_key(Foo::key_a()), &2);
+    assert_eq!(foo.get_key(Foo::key_b()), &[2, 3]);
+
+    let mut bar = Bar("a", vec!["b"]);
+    *bar.get_key_mut(Bar::key_0()) = "c";
+    assert_eq!(bar.get_key(Bar::key_0()), &"c");
+    assert_eq!(bar.get_key(Bar::key_1()), &["b"]);
+
+    assert_eq!(Foo::<u8>::key_b().name(), "b");
+    assert_eq!(Foo::<u8>::key_b().index(), 1);
+    assert_eq!(Bar::<u8>::key_1().name(), "1");
+}
+
+#[test]
+fn dynamic_access() {
+    #[derive(FieldAccess)]
+    struct Limits {
+        max_connections: u32,
+    }
+
+    #[derive(FieldAccess)]
+    #[field_access(keys, rename_all = "camelCase")]
+    struct Foo {
+        #[field_access(alias = "hostname")]
+        host: &'static str,
+        #[field_access(skip)]
+        _skipped: bool,
+        #[field_access(readonly)]
+        id: u64,
+        #[field_access(flatten)]
+        limits: Limits,
+        #[field_access(writeonly)]
+        secret_key: &'static str,
+        #[field_access(rename = "listen-port")]
+        r#port: u16,
+    }
+
+    let foo = Foo {
+        host: "localhost",
+        _skipped: false,
+        id: 1,
+        limits: Limits { max_connections: 1 },
+        secret_key: "",
+        r#port: 80,
+    };
+
+    assert_eq!(Foo::key_host().name(), "host");
+    assert_eq!(Foo::key_id().name(), "id");
+    assert_eq!(Foo::key_secret_key().name(), "secretKey");
+    assert_eq!(Foo::key_port().name(), "listen-port");
+
+    assert_eq!(Foo::key_host().index(), 0);
+    assert_eq!(Foo::key_id().index(), 1);
+    assert_eq!(Foo::key_secret_key().index(), 2);
+    assert_eq!(Foo::key_port().index(), 3);
+
+    assert_eq!(
+        foo.field(Foo::key_port().name()).unwrap().as_u16(),
+        Some(80)
+    );
+    assert_eq!(
+        foo.field_at(Foo::key_id().index()).unwrap().downcast_ref(),
+        Some(&1u64)
+    );
+    assert_eq!(Foo::FIELDS[Foo::key_port().index()].name(), "listen-port");
+    assert_eq!(
+        foo.field_index(Foo::key_port().name()),
+        Some(Foo::key_port().index())
+    );
+}
+
+#[test]
+fn debug() {
+    #[derive(FieldAccess)]
+    #[field_access(keys)]
+    struct Foo {
+        a: u8,
+        #[field_access(rename = "listen-port")]
+        b: u16,
+    }
+
+    let foo = Foo { a: 1, b: 2 };
+
+    assert_eq!(foo.get_key(Foo::key_a()), &1);
+    assert_eq!(
+        format!("{:?}", Foo::key_b()),
+        r#"FieldKey { name: "listen-port", index: 1, .. }"#
+    );
+}
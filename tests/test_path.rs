@@ -0,0 +1,389 @@
+use field_access::{AnyFieldAccess, FieldAccess, PathErrorKind};
+
+#[derive(FieldAccess, Default)]
+struct Tls {
+    #[field_access(alias = "cert")]
+    cert_path: &'static str,
+    #[field_access(readonly)]
+    verify: bool,
+}
+
+#[derive(FieldAccess, Default)]
+struct Server {
+    tls: Tls,
+    port: u16,
+    #[field_access(readonly)]
+    limits: Limits,
+}
+
+#[derive(FieldAccess, Default)]
+struct Limits {
+    max_connections: u32,
+}
+
+#[derive(FieldAccess, Default)]
+struct Config {
+    server: Server,
+    #[field_access(flatten)]
+    extra: Extra,
+}
+
+#[derive(FieldAccess, Default)]
+struct Extra {
+    fallback: Server,
+}
+
+#[derive(FieldAccess)]
+enum Listener {
+    Tcp { server: Server },
+    Unix(Server),
+}
+
+#[derive(FieldAccess)]
+struct Wrapper<T> {
+    inner: T,
+}
+
+#[test]
+fn field_as_field_access() {
+    let mut config = Config::default();
+
+    assert!(config.field_as_field_access("server").is_some());
+    assert!(config.field_as_field_access("fallback").is_some());
+    assert!(config.field_as_field_access("extra").is_none());
+    assert!(config.field_as_field_access("missing").is_none());
+
+    let server = config.field_as_field_access("server").unwrap();
+    assert_eq!(server.field_names(), &["tls", "port", "limits"]);
+    assert!(server.field_as_field_access("port").is_none());
+
+    let server = config.field_as_field_access_mut("server").unwrap();
+    assert!(server.field_as_field_access_mut("tls").is_some());
+    assert!(server.field_as_field_access_mut("limits").is_none());
+    assert!(server.field_as_field_access("limits").is_some());
+}
+
+#[test]
+fn field_as_field_access_generic() {
+    let wrapper = Wrapper {
+        inner: Tls::default(),
+    };
+
+    // Generic fields are not detected as `AnyFieldAccess`.
+    assert!(wrapper.field_as_field_access("inner").is_none());
+    assert!(wrapper.field_as_any("inner").is_some());
+}
+
+#[test]
+fn field_path() {
+    let mut config = Config::default();
+    config.server.tls.cert_path = "cert.pem";
+    config.server.port = 443;
+
+    assert_eq!(
+        config.field_path("server.tls.cert_path").unwrap().as_str(),
+        Some("cert.pem")
+    );
+    assert_eq!(
+        config.field_path("server.tls.cert").unwrap().as_str(),
+        Some("cert.pem")
+    );
+    assert_eq!(
+        config.field_path("server.port").unwrap().as_u16(),
+        Some(443)
+    );
+    assert!(config.field_path("fallback.port").is_ok());
+    assert!(config.field_path("server.limits.max_connections").is_ok());
+    assert!(config.field_path("server.tls.verify").is_ok());
+}
+
+#[test]
+fn field_path_mut() {
+    let mut config = Config::default();
+
+    assert!(config
+        .field_path_mut("server.tls.cert_path")
+        .unwrap()
+        .set("cert.pem"));
+    assert!(config.field_path_mut("fallback.port").unwrap().set(8080u16));
+    assert_eq!(config.server.tls.cert_path, "cert.pem");
+    assert_eq!(config.extra.fallback.port, 8080);
+
+    let err = config.field_path_mut("server.tls.verify").unwrap_err();
+    assert_eq!(err.kind(), PathErrorKind::NoSuchField);
+
+    let err = config
+        .field_path_mut("server.limits.max_connections")
+        .unwrap_err();
+    assert_eq!(err.segment(), "limits");
+    assert_eq!(err.kind(), PathErrorKind::NoSuchField);
+}
+
+#[test]
+fn field_path_errors() {
+    let config = Config::default();
+
+    let err = config.field_path("server.tls.key_path").unwrap_err();
+    assert_eq!(err.path(), "server.tls.key_path");
+    assert_eq!(err.segment(), "key_path");
+    assert_eq!(err.segment_range(), 11..19);
+    assert_eq!(err.kind(), PathErrorKind::NoSuchField);
+    assert_eq!(
+        err.to_string(),
+        "no such field `key_path` in path `server.tls.key_path`"
+    );
+
+    let err = config.field_path("server.port.value").unwrap_err();
+    assert_eq!(err.segment(), "port");
+    assert_eq!(err.kind(), PathErrorKind::NotFieldAccess);
+    assert_eq!(
+        err.to_string(),
+        "field `port` in path `server.port.value` has no nested fields"
+    );
+
+    let err = config.field_path("client.port").unwrap_err();
+    assert_eq!(err.segment(), "client");
+    assert_eq!(err.kind(), PathErrorKind::NoSuchField);
+
+    let err = config.field_path("server..port").unwrap_err();
+    assert_eq!(err.segment(), "");
+    assert_eq!(err.segment_range(), 7..7);
+
+    assert!(config.field_path("").is_err());
+}
+
+#[test]
+fn enum_field_path() {
+    let mut listener = Listener::Tcp {
+        server: Server::default(),
+    };
+
+    assert!(listener.field_path_mut("server.port").unwrap().set(80u16));
+    assert_eq!(
+        listener.field_path("server.port").unwrap().as_u16(),
+        Some(80)
+    );
+
+    let listener = Listener::Unix(Server::default());
+
+    assert!(listener.field_path("0.tls.cert_path").is_ok());
+    assert!(listener.field_path("server.port").is_err());
+}
+
+#[test]
+fn field_as_field_access_recursive() {
+    fn collect(prefix: &str, access: &dyn AnyFieldAccess, paths: &mut Vec<String>) {
+        for name in access.field_names() {
+            let path = format!("{prefix}{name}");
+            let field = access.field(name).unwrap();
+
+            match field.as_field_access() {
+                Some(nested) => collect(&format!("{path}."), nested, paths),
+                None => paths.push(path),
+            }
+        }
+    }
+
+    let config = Config::default();
+    let mut paths = Vec::new();
+    collect("", &config, &mut paths);
+
+    assert_eq!(
+        paths,
+        &[
+            "server.tls.cert_path",
+            "server.tls.verify",
+            "server.port",
+            "server.limits.max_connections",
+        ]
+    );
+}
+
+#[test]
+fn nested_field_value() {
+    let mut server = Server::default();
+
+    let field = server.field("tls").unwrap();
+    assert!(field.is::<Tls>());
+    assert!(field.as_field_access().is_some());
+    assert_eq!(field.get::<Tls>().unwrap().cert_path, "");
+
+    let mut field = server.field_mut("tls").unwrap();
+    field.get_mut::<Tls>().unwrap().cert_path = "cert.pem";
+    assert!(field.as_field_access().is_some());
+    assert!(field
+        .as_field_access_mut()
+        .unwrap()
+        .field_mut("cert_path")
+        .unwrap()
+        .set("other.pem"));
+    assert!(field.set(Tls {
+        cert_path: "new.pem",
+        verify: true,
+    }));
+    assert_eq!(server.tls.cert_path, "new.pem");
+
+    assert!(server.field_mut("limits").is_none());
+    assert!(server.field("port").unwrap().as_field_access().is_none());
+}
+
+#[derive(FieldAccess, Default)]
+struct Cluster {
+    servers: Vec<Server>,
+    tags: Vec<&'static str>,
+    ports: [u16; 2],
+    names: &'static [&'static str],
+    #[field_access(readonly)]
+    replicas: Vec<Server>,
+}
+
+fn cluster() -> Cluster {
+    Cluster {
+        servers: vec![Server::default(), Server::default()],
+        tags: vec!["a", "b"],
+        ports: [80, 443],
+        names: &["x", "y"],
+        replicas: vec![Server::default()],
+    }
+}
+
+#[test]
+fn field_as_sequence() {
+    let mut cluster = cluster();
+
+    let servers = cluster.field_as_sequence("servers").unwrap();
+    assert_eq!(servers.len(), 2);
+    assert!(!servers.is_empty());
+    assert!(servers.element(0).unwrap().as_field_access().is_some());
+    assert!(servers.element(2).is_none());
+
+    let tags = cluster.field_as_sequence("tags").unwrap();
+    assert_eq!(tags.element(1).unwrap().as_str(), Some("b"));
+    assert!(tags.element(1).unwrap().as_field_access().is_none());
+
+    assert_eq!(
+        cluster
+            .field_as_sequence("names")
+            .unwrap()
+            .element(0)
+            .unwrap()
+            .as_str(),
+        Some("x")
+    );
+    assert!(cluster.field_as_sequence("missing").is_none());
+    assert!(cluster.field_as_sequence("replicas").is_some());
+
+    let ports = cluster.field_as_sequence_mut("ports").unwrap();
+    assert!(ports.element_mut(1).unwrap().set(8443u16));
+    assert_eq!(cluster.ports, [80, 8443]);
+
+    let names = cluster.field_as_sequence_mut("names").unwrap();
+    assert_eq!(names.len(), 2);
+    assert!(names.element_mut(0).is_none());
+    assert!(cluster.field_as_sequence_mut("replicas").is_none());
+
+    let config = Config::default();
+    assert!(config.field_as_sequence("server").is_none());
+}
+
+#[test]
+fn indexed_field_path() {
+    let mut cluster = cluster();
+    cluster.servers[1].port = 443;
+    cluster.servers[1].tls.cert_path = "cert.pem";
+
+    assert_eq!(
+        cluster.field_path("servers[1].port").unwrap().as_u16(),
+        Some(443)
+    );
+    assert_eq!(
+        cluster
+            .field_path("servers[1].tls.cert_path")
+            .unwrap()
+            .as_str(),
+        Some("cert.pem")
+    );
+    assert_eq!(cluster.field_path("tags[0]").unwrap().as_str(), Some("a"));
+    assert_eq!(cluster.field_path("ports[1]").unwrap().as_u16(), Some(443));
+    assert_eq!(cluster.field_path("names[1]").unwrap().as_str(), Some("y"));
+    assert!(cluster
+        .field_path("servers[0]")
+        .unwrap()
+        .as_field_access()
+        .is_some());
+}
+
+#[test]
+fn indexed_field_path_mut() {
+    let mut cluster = cluster();
+
+    assert!(cluster
+        .field_path_mut("servers[0].tls.cert_path")
+        .unwrap()
+        .set("cert.pem"));
+    assert!(cluster.field_path_mut("tags[1]").unwrap().set("c"));
+    assert!(cluster.field_path_mut("ports[0]").unwrap().set(8080u16));
+    assert_eq!(cluster.servers[0].tls.cert_path, "cert.pem");
+    assert_eq!(cluster.tags, &["a", "c"]);
+    assert_eq!(cluster.ports, [8080, 443]);
+
+    let err = cluster.field_path_mut("names[0]").unwrap_err();
+    assert_eq!(err.kind(), PathErrorKind::NotMutable);
+    assert_eq!(
+        err.to_string(),
+        "elements of `names[0]` in path `names[0]` are not mutable"
+    );
+
+    let err = cluster.field_path_mut("names[2]").unwrap_err();
+    assert_eq!(err.kind(), PathErrorKind::IndexOutOfBounds);
+
+    let err = cluster.field_path_mut("replicas[0].port").unwrap_err();
+    assert_eq!(err.kind(), PathErrorKind::NoSuchField);
+}
+
+#[test]
+fn indexed_field_path_errors() {
+    let cluster = cluster();
+
+    let err = cluster.field_path("servers[2].port").unwrap_err();
+    assert_eq!(err.segment(), "servers[2]");
+    assert_eq!(err.kind(), PathErrorKind::IndexOutOfBounds);
+    assert_eq!(
+        err.to_string(),
+        "index out of bounds in `servers[2]` in path `servers[2].port`"
+    );
+
+    let err = cluster.field_path("tags[0].len").unwrap_err();
+    assert_eq!(err.segment(), "tags[0]");
+    assert_eq!(err.kind(), PathErrorKind::NotFieldAccess);
+
+    let err = cluster.field_path("servers[0].port[0]").unwrap_err();
+    assert_eq!(err.segment(), "port[0]");
+    assert_eq!(err.segment_range(), 11..18);
+    assert_eq!(err.kind(), PathErrorKind::NotSequence);
+    assert_eq!(
+        err.to_string(),
+        "field `port[0]` in path `servers[0].port[0]` is not a sequence"
+    );
+
+    let err = cluster.field_path("hosts[0]").unwrap_err();
+    assert_eq!(err.kind(), PathErrorKind::NoSuchField);
+
+    for path in [
+        "servers[",
+        "servers[]",
+        "servers[a]",
+        "servers[-1]",
+        "servers]",
+        "servers[0][1]",
+    ] {
+        let err = cluster.field_path(path).unwrap_err();
+        assert_eq!(err.segment(), path);
+        assert_eq!(err.kind(), PathErrorKind::InvalidSegment);
+    }
+
+    assert_eq!(
+        cluster.field_path("servers.port").unwrap_err().kind(),
+        PathErrorKind::NotFieldAccess
+    );
+}
@@ -0,0 +1,160 @@
+use field_access::{AnyFieldAccess, FieldAccess, PathErrorKind};
+
+#[derive(FieldAccess, Default)]
+struct Tls {
+    #[field_access(rename = "cert~path", alias = "cert/path")]
+    cert_path: &'static str,
+}
+
+#[derive(FieldAccess, Default)]
+struct Server {
+    tls: Tls,
+    ports: Vec<u16>,
+    #[field_access(readonly)]
+    id: u32,
+}
+
+#[derive(FieldAccess, Default)]
+struct Config {
+    servers: Vec<Server>,
+    names: &'static [&'static str],
+    #[field_access(flatten)]
+    main: Server,
+}
+
+fn config() -> Config {
+    Config {
+        servers: vec![
+            Server {
+                ports: vec![80, 443],
+                ..Server::default()
+            },
+            Server::default(),
+        ],
+        names: &["a", "b"],
+        main: Server {
+            tls: Tls {
+                cert_path: "cert.pem",
+            },
+            ports: vec![22],
+            id: 1,
+        },
+    }
+}
+
+#[test]
+fn pointer() {
+    let config = config();
+
+    assert_eq!(
+        config.pointer("/servers/0/ports/1").unwrap().as_u16(),
+        Some(443)
+    );
+    assert_eq!(config.pointer("/names/1").unwrap().as_str(), Some("b"));
+    assert_eq!(config.pointer("/ports/0").unwrap().as_u16(), Some(22));
+    assert_eq!(
+        config.pointer("/tls/cert~0path").unwrap().as_str(),
+        Some("cert.pem")
+    );
+    assert_eq!(
+        config.pointer("/tls/cert~1path").unwrap().as_str(),
+        Some("cert.pem")
+    );
+    assert_eq!(
+        config
+            .pointer("/servers")
+            .unwrap()
+            .as_slice::<Server>()
+            .map(<[_]>::len),
+        Some(2)
+    );
+    assert!(config
+        .pointer("/servers/1")
+        .unwrap()
+        .as_field_access()
+        .is_some());
+
+    let root = config.pointer("").unwrap();
+    assert!(root.is::<Config>());
+    assert_eq!(
+        root.as_field_access().unwrap().field_names(),
+        config.field_names()
+    );
+}
+
+#[test]
+fn pointer_mut() {
+    let mut config = config();
+
+    assert!(config
+        .pointer_mut("/servers/1/ports")
+        .unwrap()
+        .set(vec![8080u16]));
+    assert!(config
+        .pointer_mut("/servers/1/ports/0")
+        .unwrap()
+        .set(8081u16));
+    assert!(config
+        .pointer_mut("/tls/cert~0path")
+        .unwrap()
+        .set("other.pem"));
+    assert_eq!(config.servers[1].ports, &[8081]);
+    assert_eq!(config.main.tls.cert_path, "other.pem");
+
+    assert!(config
+        .pointer_mut("")
+        .unwrap()
+        .as_field_access_mut()
+        .is_some());
+
+    let err = config.pointer_mut("/id").unwrap_err();
+    assert_eq!(err.kind(), PathErrorKind::NoSuchField);
+
+    let err = config.pointer_mut("/names/0").unwrap_err();
+    assert_eq!(err.kind(), PathErrorKind::NotMutable);
+
+    let err = config.pointer_mut("/names/2").unwrap_err();
+    assert_eq!(err.kind(), PathErrorKind::IndexOutOfBounds);
+}
+
+#[test]
+fn pointer_errors() {
+    let config = config();
+
+    let err = config.pointer("/servers/2/tls").unwrap_err();
+    assert_eq!(err.segment(), "2");
+    assert_eq!(err.segment_range(), 9..10);
+    assert_eq!(err.kind(), PathErrorKind::IndexOutOfBounds);
+
+    let err = config.pointer("/servers/-").unwrap_err();
+    assert_eq!(err.kind(), PathErrorKind::IndexOutOfBounds);
+
+    for pointer in ["/servers/01", "/servers/", "/servers/x", "/servers/+1"] {
+        let err = config.pointer(pointer).unwrap_err();
+        assert_eq!(err.kind(), PathErrorKind::InvalidSegment, "{pointer}");
+    }
+
+    let err = config.pointer("servers").unwrap_err();
+    assert_eq!(err.segment(), "servers");
+    assert_eq!(err.kind(), PathErrorKind::InvalidSegment);
+
+    let err = config.pointer("/tls/cert~2path").unwrap_err();
+    assert_eq!(err.segment(), "cert~2path");
+    assert_eq!(err.kind(), PathErrorKind::InvalidSegment);
+
+    let err = config.pointer("/tls/key~0path").unwrap_err();
+    assert_eq!(err.kind(), PathErrorKind::NoSuchField);
+
+    let err = config.pointer("/id/0").unwrap_err();
+    assert_eq!(err.segment(), "id");
+    assert_eq!(err.kind(), PathErrorKind::NotFieldAccess);
+
+    let err = config.pointer("/servers/0/ports/0/1").unwrap_err();
+    assert_eq!(err.segment(), "0");
+    assert_eq!(err.segment_range(), 17..18);
+    assert_eq!(err.kind(), PathErrorKind::NotFieldAccess);
+
+    let err = config.pointer("/").unwrap_err();
+    assert_eq!(err.segment(), "");
+    assert_eq!(err.kind(), PathErrorKind::NoSuchField);
+}
@@ -0,0 +1,397 @@
+use core::any::TypeId;
+use field_access::{AnyFieldAccess, FieldAccess, FieldInfo, MetaValue};
+
+fn summary(fields: &[FieldInfo]) -> Vec<(&'static str, usize, Option<&'static str>)> {
+    fields
+        .iter()
+        .map(|field| (field.name(), field.index(), field.variant()))
+        .collect()
+}
+
+#[test]
+fn struct_fields() {
+    #[derive(FieldAccess)]
+    struct Foo {
+        #[field_access(rename = "hostname", alias = "host")]
+        name: String,
+        #[field_access(readonly)]
+        id: u64,
+        #[field_access(writeonly)]
+        secret: Option<u8>,
+        #[field_access(skip)]
+        _skipped: bool,
+    }
+
+    let fields = Foo::FIELDS;
+
+    assert_eq!(
+        summary(fields),
+        [("hostname", 0, None), ("id", 1, None), ("secret", 2, None)]
+    );
+
+    let [name, id, secret] = fields else {
+        panic!("unexpected fields: {fields:?}")
+    };
+
+    assert_eq!(name.aliases(), &["host"]);
+    assert!(name.is_readable() && name.is_writable());
+    assert!(id.aliases().is_empty());
+    assert!(id.is_readable() && !id.is_writable());
+    assert!(!secret.is_readable() && secret.is_writable());
+
+    assert_eq!(name.ty().ty(), "String");
+    assert_eq!(id.ty().type_id(), TypeId::of::<u64>());
+    assert_eq!(secret.ty().ty(), "Option<u8>");
+}
+
+#[test]
+fn generic_struct_fields() {
+    #[derive(FieldAccess)]
+    struct Foo<T> {
+        value: Option<T>,
+    }
+
+    assert_eq!(Foo::<u8>::FIELDS[0].ty().ty(), "Option<T>");
+    assert_eq!(
+        Foo::<u8>::FIELDS[0].ty().type_name(),
+        "core::option::Option<u8>"
+    );
+    assert_eq!(
+        Foo::<&str>::FIELDS[0].ty().type_id(),
+        TypeId::of::<Option<&str>>()
+    );
+}
+
+#[test]
+fn flattened_fields() {
+    #[derive(FieldAccess)]
+    struct Limits {
+        max_connections: u32,
+    }
+
+    #[derive(FieldAccess)]
+    struct Foo {
+        port: u16,
+        #[field_access(flatten)]
+        limits: Limits,
+    }
+
+    // Flattened fields belong to the inner type's schema.
+    assert_eq!(summary(Foo::FIELDS), [("port", 0, None)]);
+    assert_eq!(summary(Limits::FIELDS), [("max_connections", 0, None)]);
+}
+
+#[test]
+fn enum_fields() {
+    #[derive(FieldAccess)]
+    enum Foo {
+        A { a: f64 },
+        B(u32, #[field_access(readonly)] u32),
+        C,
+    }
+
+    assert_eq!(
+        summary(Foo::FIELDS),
+        [
+            ("a", 0, Some("A")),
+            ("0", 0, Some("B")),
+            ("1", 1, Some("B"))
+        ]
+    );
+    assert!(!Foo::FIELDS[2].is_writable());
+
+    for foo in [Foo::A { a: 1.0 }, Foo::B(1, 2), Foo::C] {
+        let names: Vec<_> = Foo::FIELDS
+            .iter()
+            .filter(|field| field.variant() == foo.variant_name())
+            .map(FieldInfo::name)
+            .collect();
+        assert_eq!(foo.field_names(), names);
+    }
+}
+
+#[test]
+fn field_info() {
+    #[derive(FieldAccess)]
+    struct Limits {
+        max_connections: u32,
+    }
+
+    #[derive(FieldAccess)]
+    struct Foo {
+        #[field_access(rename = "hostname", alias = "host")]
+        name: &'static str,
+        id: u64,
+        #[field_access(skip)]
+        _skipped: bool,
+        #[field_access(flatten)]
+        limits: Limits,
+    }
+
+    let foo = Foo {
+        name: "foo",
+        id: 1,
+        _skipped: false,
+        limits: Limits { max_connections: 1 },
+    };
+
+    let info = foo.field_info("host").unwrap();
+    assert_eq!(info.name(), "hostname");
+    assert_eq!(info.index(), 0);
+    assert_eq!(foo.field_info("max_connections").unwrap().ty().ty(), "u32");
+    assert!(foo.field_info("limits").is_none());
+    assert!(foo.field_info("_skipped").is_none());
+
+    assert_eq!(
+        foo.field_names()
+            .iter()
+            .map(|name| foo.field_info(name).unwrap().index())
+            .collect::<Vec<_>>(),
+        [0, 1]
+    );
+}
+
+#[test]
+fn enum_field_info() {
+    #[derive(FieldAccess)]
+    enum Foo {
+        A { a: f64 },
+        B(u32, #[field_access(readonly)] u32),
+        C,
+    }
+
+    let foo = Foo::B(1, 2);
+    let info = foo.field_info("1").unwrap();
+    assert_eq!(info.variant(), Some("B"));
+    assert_eq!(info.index(), 1);
+    assert!(!info.is_writable());
+    assert!(foo.field_info("a").is_none());
+    assert!(Foo::A { a: 1.0 }.field_info("a").is_some());
+    assert!(Foo::C.field_info("0").is_none());
+}
+
+#[test]
+fn field_doc() {
+    #[derive(FieldAccess)]
+    struct Limits {
+        max_connections: u32,
+    }
+
+    /// Container docs are not collected.
+    #[derive(FieldAccess)]
+    struct Foo {
+        /// A single line.
+        single: u8,
+        /// First line.
+        ///
+        ///     indented
+        /// last line.
+        ///
+        multi: u8,
+        /** Block comment. */
+        block: u8,
+        #[doc = "Attribute."]
+        #[field_access(alias = "alias")]
+        attribute: u8,
+        #[allow(clippy::empty_docs)]
+        ///
+        empty: u8,
+        undocumented: u8,
+        /// Flattened struct.
+        #[field_access(flatten)]
+        limits: Limits,
+    }
+
+    let foo = Foo {
+        single: 0,
+        multi: 0,
+        block: 0,
+        attribute: 0,
+        empty: 0,
+        undocumented: 0,
+        limits: Limits { max_connections: 1 },
+    };
+
+    assert_eq!(foo.field_doc("single"), Some("A single line."));
+    assert_eq!(
+        foo.field_doc("multi"),
+        Some("First line.\n\n    indented\nlast line.")
+    );
+    assert_eq!(foo.field_doc("block"), Some("Block comment."));
+    assert_eq!(foo.field_doc("alias"), Some("Attribute."));
+    assert_eq!(foo.field_doc("empty"), None);
+    assert_eq!(foo.field_doc("undocumented"), None);
+    assert_eq!(foo.field_doc("max_connections"), None);
+    assert_eq!(foo.field_doc("limits"), None);
+    assert_eq!(foo.field_doc("unknown"), None);
+
+    assert_eq!(Foo::FIELDS[0].doc(), Some("A single line."));
+    assert_eq!(Foo::FIELDS[5].doc(), None);
+}
+
+#[test]
+fn field_meta() {
+    #[derive(FieldAccess)]
+    struct Limits {
+        max_connections: u32,
+    }
+
+    #[derive(FieldAccess)]
+    struct Foo {
+        #[field_access(meta(unit = "ms", restart), meta(r#type = "slider"))]
+        #[field_access(meta(min = -1, max = 9_223_372_036_854_775_807, step = 0.5, neg = -1.5e3))]
+        timeout: i64,
+        #[field_access(alias = "other", meta(enabled = false))]
+        plain: bool,
+        #[field_access(flatten)]
+        limits: Limits,
+    }
+
+    let foo = Foo {
+        timeout: 0,
+        plain: false,
+        limits: Limits { max_connections: 1 },
+    };
+
+    assert_eq!(
+        foo.field_meta("timeout"),
+        &[
+            ("unit", MetaValue::Str("ms")),
+            ("restart", MetaValue::Bool(true)),
+            ("type", MetaValue::Str("slider")),
+            ("min", MetaValue::Int(-1)),
+            ("max", MetaValue::Int(i64::MAX)),
+            ("step", MetaValue::Float(0.5)),
+            ("neg", MetaValue::Float(-1500.0)),
+        ]
+    );
+    assert_eq!(
+        foo.field_meta("other"),
+        &[("enabled", MetaValue::Bool(false))]
+    );
+    assert!(foo.field_meta("max_connections").is_empty());
+    assert!(foo.field_meta("unknown").is_empty());
+}
+
+#[test]
+fn meta_value() {
+    #[derive(FieldAccess)]
+    struct Foo {
+        #[field_access(meta(unit = "ms", restart, min = -1, step = 0.5))]
+        timeout: i64,
+    }
+
+    let timeout = &Foo::FIELDS[0];
+
+    assert_eq!(
+        timeout.meta_value("unit").and_then(MetaValue::as_str),
+        Some("ms")
+    );
+    assert_eq!(
+        timeout.meta_value("restart").and_then(MetaValue::as_bool),
+        Some(true)
+    );
+    assert_eq!(
+        timeout.meta_value("min").and_then(MetaValue::as_int),
+        Some(-1)
+    );
+    assert_eq!(
+        timeout.meta_value("min").and_then(MetaValue::as_float),
+        Some(-1.0)
+    );
+    assert_eq!(
+        timeout.meta_value("step").and_then(MetaValue::as_float),
+        Some(0.5)
+    );
+    assert_eq!(timeout.meta_value("step").and_then(MetaValue::as_int), None);
+    assert_eq!(
+        timeout.meta_value("unit").and_then(MetaValue::as_bool),
+        None
+    );
+    assert_eq!(timeout.meta_value("missing"), None);
+
+    assert_eq!(MetaValue::Str("ms").to_string(), "ms");
+    assert_eq!(MetaValue::Int(-1).to_string(), "-1");
+    assert_eq!(MetaValue::Float(0.5).to_string(), "0.5");
+    assert_eq!(MetaValue::Bool(true).to_string(), "true");
+}
+
+#[test]
+fn fields_in_group() {
+    #[derive(FieldAccess)]
+    struct Foo {
+        #[field_access(group = "network")]
+        host: &'static str,
+        #[field_access(group = "network", writeonly)]
+        token: &'static str,
+        #[field_access(group = "ui")]
+        theme: &'static str,
+    }
+
+    let foo = Foo {
+        host: "localhost",
+        token: "",
+        theme: "dark",
+    };
+
+    let names: Vec<_> = foo
+        .fields_in_group("network")
+        .map(|(name, _)| name)
+        .collect();
+    assert_eq!(names, ["host"]);
+    assert_eq!(
+        foo.fields_in_group("ui").next().unwrap().1.as_str(),
+        Some("dark")
+    );
+    assert_eq!(foo.fields_in_group("").count(), 0);
+    assert_eq!(foo.fields_in_group("unknown").count(), 0);
+
+    assert_eq!(Foo::FIELDS[1].groups(), &["network"]);
+    assert!(Foo::FIELDS[2].groups() == ["ui"]);
+}
+
+#[test]
+fn flattened_fields_in_group() {
+    #[derive(FieldAccess)]
+    struct Network {
+        #[field_access(group = "network", group = "advanced")]
+        retries: u8,
+    }
+
+    #[derive(FieldAccess)]
+    struct Foo {
+        #[field_access(group = "network")]
+        host: &'static str,
+        #[field_access(flatten)]
+        network: Network,
+    }
+
+    let foo = Foo {
+        host: "localhost",
+        network: Network { retries: 3 },
+    };
+
+    assert_eq!(
+        foo.fields_in_group("network")
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>(),
+        ["host", "retries"]
+    );
+    assert_eq!(foo.fields_in_group("network").len(), 2);
+    assert_eq!(
+        foo.fields_in_group("network")
+            .rev()
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>(),
+        ["retries", "host"]
+    );
+
+    let mut advanced = foo.fields_in_group("advanced");
+    assert_eq!(advanced.len(), 1);
+    assert_eq!(advanced.next().unwrap().1.as_u8(), Some(3));
+    assert_eq!(advanced.len(), 0);
+    assert!(advanced.next().is_none());
+
+    assert!(Network::FIELDS[0].in_group("advanced"));
+    assert!(!Network::FIELDS[0].in_group("ui"));
+}
@@ -0,0 +1,268 @@
+use core::any::{type_name, Any};
+use field_access::{AnyFieldAccess, FieldAccess};
+use std::borrow::Cow;
+
+#[test]
+fn field_types() {
+    #[derive(FieldAccess)]
+    struct Foo {
+        a: u16,
+        b: Cow<'static, str>,
+        #[field_access(skip)]
+        _c: bool,
+    }
+
+    let foo = Foo {
+        a: 1,
+        b: Cow::Borrowed("b"),
+        _c: false,
+    };
+    let types: Vec<_> = foo
+        .field_types()
+        .iter()
+        .map(|ty| (ty.name(), ty.ty(), ty.type_name()))
+        .collect();
+
+    assert_eq!(
+        types,
+        [
+            ("a", "u16", "u16"),
+            ("b", "Cow<'static, str>", type_name::<Cow<str>>()),
+        ]
+    );
+    assert_eq!(
+        foo.field_names(),
+        types.iter().map(|ty| ty.0).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn generic_field_types() {
+    #[derive(FieldAccess)]
+    struct Foo<T> {
+        a: T,
+        b: Vec<T>,
+    }
+
+    let foo = Foo { a: 1u8, b: vec![] };
+    let types: Vec<_> = foo
+        .field_types()
+        .iter()
+        .map(|ty| (ty.ty(), ty.type_name()))
+        .collect();
+
+    assert_eq!(types, [("T", "u8"), ("Vec<T>", "alloc::vec::Vec<u8>")]);
+
+    let foo = Foo {
+        a: "a",
+        b: vec!["b"],
+    };
+
+    assert_eq!(foo.field_types()[0].type_name(), "&str");
+    assert_eq!(foo.field_types()[1].type_name(), "alloc::vec::Vec<&str>");
+}
+
+#[test]
+fn enum_field_types() {
+    #[derive(FieldAccess)]
+    enum Foo {
+        A { a: f64 },
+        B(u32, Cow<'static, str>),
+        C,
+    }
+
+    let foo = Foo::A { a: 1.0 };
+    assert_eq!(foo.field_types().len(), 1);
+    assert_eq!(foo.field_types()[0].ty(), "f64");
+
+    let foo = Foo::B(1, Cow::Borrowed("b"));
+    let types: Vec<_> = foo
+        .field_types()
+        .iter()
+        .map(|ty| (ty.name(), ty.ty()))
+        .collect();
+    assert_eq!(types, [("0", "u32"), ("1", "Cow<'static, str>")]);
+
+    assert!(Foo::C.field_types().is_empty());
+}
+
+#[test]
+fn field_type() {
+    #[derive(FieldAccess)]
+    struct Limits {
+        max_connections: Option<u32>,
+    }
+
+    #[derive(FieldAccess)]
+    struct Foo<T> {
+        #[field_access(alias = "label")]
+        name: Cow<'static, str>,
+        #[field_access(writeonly)]
+        tags: Vec<T>,
+        #[field_access(skip)]
+        _skipped: bool,
+        #[field_access(flatten)]
+        limits: Limits,
+    }
+
+    let foo = Foo::<u8> {
+        name: Cow::Borrowed("foo"),
+        tags: vec![],
+        _skipped: false,
+        limits: Limits {
+            max_connections: None,
+        },
+    };
+
+    assert_eq!(foo.field_type("label").unwrap().name(), "name");
+    assert_eq!(foo.field_type("label").unwrap().ty(), "Cow<'static, str>");
+    assert_eq!(
+        foo.field_type("tags").unwrap().type_name(),
+        "alloc::vec::Vec<u8>"
+    );
+    assert_eq!(
+        foo.field_type("max_connections").unwrap().ty(),
+        "Option<u32>"
+    );
+    assert!(foo.field_type("limits").is_none());
+    assert!(foo.field_type("_skipped").is_none());
+
+    #[derive(FieldAccess)]
+    enum Bar {
+        A { a: f64 },
+        B(u32),
+    }
+
+    assert_eq!(Bar::A { a: 1.0 }.field_type("a").unwrap().ty(), "f64");
+    assert!(Bar::B(1).field_type("a").is_none());
+}
+
+#[test]
+fn field_type_name() {
+    #[derive(FieldAccess)]
+    struct Bar {
+        b: &'static str,
+    }
+
+    #[derive(FieldAccess)]
+    struct Foo<T> {
+        a: u16,
+        name: Cow<'static, str>,
+        tags: Vec<T>,
+        bar: Bar,
+    }
+
+    let mut foo = Foo {
+        a: 1,
+        name: Cow::Owned(String::from("foo")),
+        tags: vec![1u8],
+        bar: Bar { b: "b" },
+    };
+
+    assert_eq!(foo.field("a").unwrap().type_name(), Some("u16"));
+    assert_eq!(
+        foo.field("name").unwrap().type_name(),
+        Some(type_name::<Cow<str>>())
+    );
+    assert_eq!(
+        foo.field("bar").unwrap().type_name(),
+        Some("test_types::field_type_name::Bar")
+    );
+    assert_eq!(foo.field_path("bar.b").unwrap().type_name(), Some("&str"));
+    assert_eq!(
+        foo.field_mut("tags").unwrap().type_name(),
+        Some("alloc::vec::Vec<u8>")
+    );
+    assert_eq!(
+        foo.field_path_mut("tags[0]").unwrap().type_name(),
+        Some("u8")
+    );
+    assert_eq!(
+        foo.pointer("").unwrap().type_name(),
+        Some("test_types::field_type_name::Foo<u8>")
+    );
+    assert_eq!(
+        foo.field("a")
+            .unwrap()
+            .try_get::<u32>()
+            .unwrap_err()
+            .to_string(),
+        "type mismatch: expected a value of type `u32`, found `u16`"
+    );
+}
+
+#[test]
+fn manual_type_name() {
+    // Manual implementations provide their fields as `&dyn Any`.
+    struct Foo {
+        a: u8,
+    }
+
+    impl AnyFieldAccess for Foo {
+        fn field_as_any(&self, field: &str) -> Option<&dyn Any> {
+            (field == "a").then_some(&self.a)
+        }
+
+        fn field_as_any_mut(&mut self, field: &str) -> Option<&mut dyn Any> {
+            (field == "a").then_some(&mut self.a)
+        }
+
+        fn field_names(&self) -> &'static [&'static str] {
+            &["a"]
+        }
+    }
+
+    let foo = Foo { a: 1 };
+    let field = foo.field("a").unwrap();
+
+    assert_eq!(field.type_name(), None);
+    assert_eq!(
+        field.try_get::<u32>().unwrap_err().to_string(),
+        "type mismatch: expected a value of type `u32`"
+    );
+}
+
+#[test]
+fn declared_types() {
+    #[derive(FieldAccess)]
+    #[allow(clippy::type_complexity)]
+    struct Foo {
+        a: std::collections::HashMap<String, Vec<Vec<u8>>>,
+        b: (u8, &'static [u16; 4]),
+        c: Option<fn(u8) -> u8>,
+        d: Box<dyn Fn(&str) -> Option<u8> + Send>,
+        e: <Vec<u8> as IntoIterator>::Item,
+        f: *const [u8],
+        g: &'static mut [u8],
+        h: Box<dyn Iterator<Item = u8>>,
+        i: Box<dyn for<'a> Fn(&'a u8) -> &'a u8 + Send + 'static>,
+    }
+
+    let foo = Foo {
+        a: Default::default(),
+        b: (0, &[0; 4]),
+        c: None,
+        d: Box::new(|_| None),
+        e: 0,
+        f: &[],
+        g: &mut [],
+        h: Box::new(std::iter::empty()),
+        i: Box::new(|value| value),
+    };
+    let types: Vec<_> = foo.field_types().iter().map(|ty| ty.ty()).collect();
+
+    assert_eq!(
+        types,
+        [
+            "std::collections::HashMap<String, Vec<Vec<u8>>>",
+            "(u8, &'static [u16; 4])",
+            "Option<fn(u8) -> u8>",
+            "Box<dyn Fn(&str) -> Option<u8> + Send>",
+            "<Vec<u8> as IntoIterator>::Item",
+            "*const [u8]",
+            "&'static mut [u8]",
+            "Box<dyn Iterator<Item = u8>>",
+            "Box<dyn for<'a> Fn(&'a u8) -> &'a u8 + Send + 'static>",
+        ]
+    );
+}
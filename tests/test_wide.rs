@@ -0,0 +1,179 @@
+use field_access::{AnyFieldAccess, FieldAccess};
+
+// Structs with many fields use a different lookup strategy than small ones. The names are chosen
+// so that many of them share their length and prefix.
+
+#[derive(FieldAccess, Default)]
+struct Disk {
+    disk_read: u64,
+    disk_write: u64,
+}
+
+#[derive(FieldAccess, Default)]
+struct Telemetry {
+    cpu_user: u64,
+    cpu_system: u64,
+    cpu_idle: u64,
+    cpu_iowait: u64,
+    cpu_steal: u64,
+    cpu_nice: u64,
+    mem_used: u64,
+    mem_free: u64,
+    mem_cached: u64,
+    mem_buffers: u64,
+    #[field_access(alias = "swap")]
+    swap_used: u64,
+    swap_free: u64,
+    net_rx_bytes: u64,
+    net_tx_bytes: u64,
+    net_rx_packets: u64,
+    net_tx_packets: u64,
+    net_rx_errors: u64,
+    net_tx_errors: u64,
+    #[field_access(readonly)]
+    metric_000: u32,
+    metric_001: u32,
+    metric_010: u32,
+    metric_011: u32,
+    metric_100: u32,
+    metric_101: u32,
+    metric_110: u32,
+    #[field_access(writeonly)]
+    metric_111: u32,
+    #[field_access(rename = "m", alias = "n", alias = "o")]
+    short: u8,
+    #[field_access(flatten)]
+    disk: Disk,
+    nested: Disk,
+}
+
+#[derive(FieldAccess)]
+enum Sample {
+    Small {
+        value: u64,
+    },
+    Wide {
+        a00: u8,
+        a01: u8,
+        a02: u8,
+        a03: u8,
+        a04: u8,
+        a05: u8,
+        a06: u8,
+        a07: u8,
+        b00: u8,
+        b01: u8,
+        b02: u8,
+        b03: u8,
+        b04: u8,
+        b05: u8,
+        b06: u8,
+        b07: u8,
+        c00: u8,
+    },
+}
+
+#[test]
+fn field() {
+    let mut telemetry = Telemetry::default();
+
+    for (index, name) in telemetry.field_names().iter().enumerate() {
+        let access = Telemetry::FIELDS[index];
+
+        assert_eq!(
+            telemetry.field(name).is_some(),
+            access.is_readable(),
+            "{name}"
+        );
+        assert_eq!(
+            telemetry.field_mut(name).is_some(),
+            access.is_writable(),
+            "{name}"
+        );
+        assert_eq!(telemetry.field_index(name), Some(index));
+        assert_eq!(telemetry.field_info(name).unwrap().name(), *name);
+
+        for alias in access.aliases() {
+            assert_eq!(telemetry.field_index(alias), Some(index));
+            assert_eq!(telemetry.field_aliases(name), access.aliases());
+        }
+    }
+
+    telemetry.field_mut("metric_111").unwrap().set(7u32);
+    telemetry.field_mut("swap").unwrap().set(8u64);
+    telemetry.field_mut("o").unwrap().set(9u8);
+    telemetry.field_mut("disk_write").unwrap().set(10u64);
+
+    assert_eq!(telemetry.metric_111, 7);
+    assert_eq!(telemetry.field("swap_used").unwrap().as_u64(), Some(8));
+    assert_eq!(telemetry.field("n").unwrap().as_u8(), Some(9));
+    assert_eq!(telemetry.field("disk_write").unwrap().as_u64(), Some(10));
+    assert_eq!(
+        telemetry.field_info("disk_read").unwrap().name(),
+        "disk_read"
+    );
+    assert!(telemetry.field_as_field_access("nested").is_some());
+    assert!(telemetry.field_as_field_access("metric_101").is_none());
+}
+
+#[test]
+fn unknown_field() {
+    let telemetry = Telemetry::default();
+
+    for name in [
+        "",
+        "x",
+        "short",
+        "disk",
+        "cpu_use",
+        "cpu_users",
+        "cpu_usor",
+        "metric_00",
+        "metric_002",
+        "metric_200",
+        "metric_0000",
+        "net_rx_bytez",
+        "NET_RX_BYTES",
+    ] {
+        assert!(telemetry.field(name).is_none(), "{name}");
+        assert!(telemetry.field_index(name).is_none(), "{name}");
+        assert!(telemetry.field_info(name).is_none(), "{name}");
+        assert!(telemetry.field_aliases(name).is_empty(), "{name}");
+    }
+}
+
+#[test]
+fn enum_variant() {
+    let small = Sample::Small { value: 1 };
+    let wide = Sample::Wide {
+        a00: 0,
+        a01: 1,
+        a02: 2,
+        a03: 3,
+        a04: 4,
+        a05: 5,
+        a06: 6,
+        a07: 7,
+        b00: 8,
+        b01: 9,
+        b02: 10,
+        b03: 11,
+        b04: 12,
+        b05: 13,
+        b06: 14,
+        b07: 15,
+        c00: 16,
+    };
+
+    assert_eq!(small.field("value").unwrap().as_u64(), Some(1));
+    assert!(small.field("a00").is_none());
+    assert!(wide.field("value").is_none());
+
+    for (index, name) in wide.field_names().iter().enumerate() {
+        assert_eq!(wide.field(name).unwrap().as_u8(), Some(index as u8));
+        assert_eq!(wide.field_index(name), Some(index));
+    }
+
+    assert!(wide.field("c01").is_none());
+    assert!(wide.field("d00").is_none());
+}
@@ -0,0 +1,10 @@
+use field_access::FieldAccess;
+
+#[derive(FieldAccess)]
+pub struct Foo {
+    a: u8,
+    #[field_access(alias = "a")]
+    b: u8,
+}
+
+fn main() {}
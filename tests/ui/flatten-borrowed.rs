@@ -0,0 +1,15 @@
+use field_access::FieldAccess;
+
+#[derive(FieldAccess)]
+pub struct Inner {
+    a: u8,
+}
+
+#[derive(FieldAccess)]
+pub struct Foo<'a> {
+    #[field_access(flatten)]
+    inner: Inner,
+    b: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use field_access::FieldAccess;
+
+#[derive(FieldAccess)]
+pub struct Inner {
+    secret: u8,
+}
+
+#[derive(FieldAccess)]
+pub struct Foo {
+    #[field_access(flatten, writeonly)]
+    inner: Inner,
+}
+
+fn main() {}
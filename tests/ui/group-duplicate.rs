@@ -0,0 +1,9 @@
+use field_access::FieldAccess;
+
+#[derive(FieldAccess)]
+pub struct Foo {
+    #[field_access(group = "a", group = "a")]
+    a: u8,
+}
+
+fn main() {}
@@ -1,9 +1,9 @@
 use field_access::FieldAccess;
 
 #[derive(FieldAccess)]
-pub enum Enum {
-    A(i64),
-    B(u8),
+#[field_access(keys)]
+enum Foo {
+    A { a: u8 },
 }
 
 fn main() {}
@@ -1,8 +1,9 @@
 use field_access::FieldAccess;
 
 #[derive(FieldAccess)]
-pub struct Foo<T> {
-    a: T,
+#[field_access(keys)]
+struct Foo<'a> {
+    a: &'a str,
 }
 
 fn main() {}
@@ -0,0 +1,9 @@
+use field_access::FieldAccess;
+
+#[derive(FieldAccess)]
+pub struct Foo {
+    #[field_access(meta(unit = "ms"), meta(unit = "s"))]
+    a: u8,
+}
+
+fn main() {}
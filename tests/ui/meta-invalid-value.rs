@@ -0,0 +1,9 @@
+use field_access::FieldAccess;
+
+#[derive(FieldAccess)]
+pub struct Foo {
+    #[field_access(meta(max = u8::MAX))]
+    a: u8,
+}
+
+fn main() {}
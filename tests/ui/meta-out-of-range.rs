@@ -0,0 +1,9 @@
+use field_access::FieldAccess;
+
+#[derive(FieldAccess)]
+pub struct Foo {
+    #[field_access(meta(max = 9_223_372_036_854_775_808))]
+    a: u8,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use field_access::FieldAccess;
+
+#[derive(FieldAccess)]
+pub struct Foo<'a, 'b> {
+    a: &'a [u8],
+    b: &'b str,
+}
+
+fn main() {}
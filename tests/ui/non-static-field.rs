@@ -1,8 +1,10 @@
+use std::borrow::Cow;
+
 use field_access::FieldAccess;
 
 #[derive(FieldAccess)]
 pub struct Foo<'a> {
-    field: &'a [u8],
+    field: Cow<'a, str>,
 }
 
 fn main() {}
@@ -0,0 +1,28 @@
+use field_access::{AnyFieldAccess, FieldAccess};
+
+#[derive(FieldAccess)]
+pub enum Enum {
+    A(i64),
+    B { b: u8 },
+    C,
+}
+
+#[derive(FieldAccess)]
+pub enum Empty {}
+
+fn main() {
+    let mut value = Enum::A(1);
+    assert_eq!(value.variant_name(), Some("A"));
+    assert_eq!(value.field_names(), &["0"]);
+    assert_eq!(value.field("0").unwrap().as_i64(), Some(1));
+
+    value = Enum::B { b: 2 };
+    assert_eq!(value.variant_name(), Some("B"));
+    assert_eq!(value.field_names(), &["b"]);
+    assert!(value.field("0").is_none());
+    assert!(value.field_mut("b").unwrap().set(3u8));
+
+    value = Enum::C;
+    assert_eq!(value.variant_name(), Some("C"));
+    assert!(value.field_names().is_empty());
+}
@@ -0,0 +1,24 @@
+use field_access::FieldAccess;
+
+#[derive(FieldAccess)]
+pub struct Foo<T> {
+    a: T,
+}
+
+#[derive(FieldAccess)]
+pub struct Bar<T, U: Default>
+where
+    T: Clone,
+{
+    a: T,
+    b: U,
+}
+
+fn main() {
+    let foo = Foo { a: 1u8 };
+    assert_eq!(foo.field("a").unwrap().as_u8(), Some(1));
+
+    let bar = Bar { a: "a", b: 2u16 };
+    assert_eq!(bar.field("a").unwrap().as_str(), Some("a"));
+    assert_eq!(bar.field("b").unwrap().as_u16(), Some(2));
+}
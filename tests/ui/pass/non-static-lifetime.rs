@@ -0,0 +1,12 @@
+use field_access::{BorrowedFieldAccess, FieldAccess};
+
+#[derive(FieldAccess)]
+pub struct Foo<'a> {
+    field: &'a [u8],
+}
+
+fn main() {
+    let buf = vec![1, 2, 3];
+    let foo = Foo { field: &buf };
+    assert_eq!(foo.field("field").unwrap().as_slice(), Some(&[1u8, 2, 3][..]));
+}
@@ -0,0 +1,19 @@
+use field_access::{AnyFieldAccess, FieldAccess};
+
+#[derive(FieldAccess)]
+pub struct Rgb(u8, u8, u8);
+
+#[derive(FieldAccess)]
+pub struct Meters(f64);
+
+fn main() {
+    let mut rgb = Rgb(1, 2, 3);
+    assert_eq!(rgb.field_names(), &["0", "1", "2"]);
+    assert_eq!(rgb.field("1").unwrap().as_u8(), Some(2));
+    assert!(rgb.field_mut("2").unwrap().set(42u8));
+    assert_eq!(rgb.2, 42);
+
+    let meters = Meters(1.5);
+    assert_eq!(meters.field_names(), &["0"]);
+    assert_eq!(meters.field("0").unwrap().as_f64(), Some(1.5));
+}
@@ -0,0 +1,12 @@
+use field_access::{AnyFieldAccess, FieldAccess};
+
+#[derive(FieldAccess)]
+pub struct Marker;
+
+fn main() {
+    let mut marker = Marker;
+    assert!(marker.field_names().is_empty());
+    assert!(marker.field("0").is_none());
+    assert!(marker.field_mut("0").is_none());
+    assert_eq!(marker.fields().count(), 0);
+}
@@ -0,0 +1,9 @@
+use field_access::FieldAccess;
+
+#[derive(FieldAccess)]
+pub struct Foo {
+    #[field_access(readonly, writeonly)]
+    a: u8,
+}
+
+fn main() {}
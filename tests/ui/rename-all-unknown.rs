@@ -0,0 +1,9 @@
+use field_access::FieldAccess;
+
+#[derive(FieldAccess)]
+#[field_access(rename_all = "Title Case")]
+pub struct Foo {
+    a: u8,
+}
+
+fn main() {}